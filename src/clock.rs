@@ -0,0 +1,110 @@
+//! Sources of the current time, so that code which needs it can be tested at a
+//! fixed instant.
+//!
+//! Every constructor which reads the system clock, such as `DateTuple::today()`,
+//! has a `_with_clock()` variant taking a `Clock` instead. `SystemClock` reads the
+//! system clock, `FixedClock` stays at one instant until moved, and `SteppingClock`
+//! moves forward by a fixed step each time it is read.
+//!
+//! ```
+//! # use date_time::clock::FixedClock;
+//! # use date_time::date_time_tuple::DateTimeTuple;
+//! # use date_time::date_tuple::DateTuple;
+//! let clock = FixedClock::new(DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z").unwrap());
+//! assert_eq!(DateTuple::new(2018, 10, 2).unwrap(), DateTuple::today_with_clock(&clock));
+//! ```
+
+use date_time_tuple::DateTimeTuple;
+use date_utils;
+use std::sync::Mutex;
+use time_tuple::Duration;
+
+/// A source of the current date and time.
+pub trait Clock {
+    /// Gets the current date and time in UTC.
+    fn now(&self) -> DateTimeTuple;
+}
+
+/// The system clock, as `std::time::SystemTime` provides it.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTimeTuple {
+        date_utils::now_as_datetimetuple()
+    }
+}
+
+/// A clock which stays at the same instant until it is set or advanced.
+#[derive(Debug)]
+pub struct FixedClock {
+    instant: Mutex<DateTimeTuple>,
+}
+
+impl FixedClock {
+    /// Produces a clock stopped at the given date and time in UTC.
+    pub fn new(instant: DateTimeTuple) -> FixedClock {
+        FixedClock {
+            instant: Mutex::new(instant),
+        }
+    }
+
+    /// Moves the clock to a different date and time in UTC.
+    pub fn set(&self, instant: DateTimeTuple) {
+        *self.instant.lock().unwrap() = instant;
+    }
+
+    /// Moves the clock forward, stopping at `DateTimeTuple::max_value()`.
+    pub fn advance(&self, duration: Duration) {
+        *self.instant.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTimeTuple {
+        *self.instant.lock().unwrap()
+    }
+}
+
+/// A clock which moves forward by a fixed step after each time it is read,
+/// stopping at `DateTimeTuple::max_value()`.
+#[derive(Debug)]
+pub struct SteppingClock {
+    next: Mutex<DateTimeTuple>,
+    step: Duration,
+}
+
+impl SteppingClock {
+    /// Produces a clock which first reads as the given date and time in UTC.
+    pub fn new(start: DateTimeTuple, step: Duration) -> SteppingClock {
+        SteppingClock {
+            next: Mutex::new(start),
+            step,
+        }
+    }
+
+    pub fn get_step(&self) -> Duration {
+        self.step
+    }
+}
+
+impl Clock for SteppingClock {
+    fn now(&self) -> DateTimeTuple {
+        let mut next = self.next.lock().unwrap();
+        let now = *next;
+        *next += self.step;
+        now
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> DateTimeTuple {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> DateTimeTuple {
+        (**self).now()
+    }
+}
@@ -1,9 +1,29 @@
+use clock::{Clock, SystemClock};
 use date_tuple::DateTuple;
-use regex::Regex;
+use date_utils;
+use error::{Error, TypeName};
+use format::{Pattern, Value};
+use locale::Locale;
+use month_tuple::MONTH_STRINGS;
+use natural;
+use parse::{self, Kind};
+use regex::{Captures, Regex};
+use relative::RelativeFormatter;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 use std::str::FromStr;
-use time_tuple::TimeTuple;
+use std::time::{self, SystemTime, UNIX_EPOCH};
+use time_tuple::{Duration, SignedDuration, TimeTuple};
+use tz::TimeZone;
+use weekday::Weekday;
+use zoned_date_time::ZonedDateTime;
+
+const SECONDS_IN_A_DAY: u64 = 86400;
+
+/// The number of seconds from `DateTimeTuple::min_value()` to the Unix epoch.
+const UNIX_EPOCH_SECONDS: i64 = 62_167_219_200;
 
 pub type DateTime = DateTimeTuple;
 
@@ -25,6 +45,81 @@ impl DateTimeTuple {
         DateTimeTuple { d, t }
     }
 
+    /// Returns a `DateTimeTuple` of the current date and time in UTC according to the
+    /// system clock.
+    ///
+    /// A system clock set outside of the range of `DateTimeTuple` gives
+    /// `DateTimeTuple::min_value()` or `DateTimeTuple::max_value()`.
+    pub fn now() -> DateTimeTuple {
+        date_utils::now_as_datetimetuple()
+    }
+
+    /// Returns a `DateTimeTuple` of the current date and time in UTC according to a clock.
+    pub fn now_with_clock<C: Clock + ?Sized>(clock: &C) -> DateTimeTuple {
+        clock.now()
+    }
+
+    /// Returns a `DateTimeTuple` of the current date and time in the local time zone,
+    /// as found by `TimeZone::local()`.
+    pub fn now_local() -> DateTimeTuple {
+        DateTimeTuple::now_local_with_clock(&SystemClock)
+    }
+
+    /// Returns a `DateTimeTuple` of the current date and time in the local time zone
+    /// according to a clock.
+    pub fn now_local_with_clock<C: Clock + ?Sized>(clock: &C) -> DateTimeTuple {
+        let now = clock.now();
+        match ZonedDateTime::from_utc(now, &TimeZone::local()) {
+            Ok(local) => local.get_date_time(),
+            Err(_) => now,
+        }
+    }
+
+    /// Converts a `std::time::SystemTime` to a date and time in UTC, including times
+    /// before 1970.
+    ///
+    /// Fractions of a second are dropped by rounding towards the past, so half a second
+    /// before 1970 becomes 1969-12-31 23:59:59. Fails with `Error::OutOfRange` if the
+    /// time is outside of the range of `DateTimeTuple`.
+    pub fn from_system_time(time: SystemTime) -> Result<DateTimeTuple, Error> {
+        let (seconds, value) = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => (
+                i64::try_from(after.as_secs()).ok(),
+                format!("{} seconds after 1970-01-01T00:00:00Z", after.as_secs()),
+            ),
+            Err(e) => {
+                let before = e.duration();
+                let rounded = before.as_secs() + if before.subsec_nanos() > 0 { 1 } else { 0 };
+                (
+                    i64::try_from(rounded).ok().map(|s| -s),
+                    format!("{} seconds before 1970-01-01T00:00:00Z", rounded),
+                )
+            }
+        };
+        seconds
+            .and_then(DateTimeTuple::checked_from_unix_seconds)
+            .ok_or_else(|| Error::OutOfRange {
+                type_name: TypeName::DateTimeTuple,
+                value,
+                min: DateTimeTuple::min_value().to_rfc3339(),
+                max: DateTimeTuple::max_value().to_rfc3339(),
+            })
+    }
+
+    /// Converts this date and time, taken as UTC, to a `std::time::SystemTime`.
+    ///
+    /// Returns `None` only if the platform cannot represent the time, such as dates
+    /// before 1601 on Windows.
+    pub fn to_system_time(self) -> Option<SystemTime> {
+        let seconds = self.to_unix_seconds();
+        let duration = time::Duration::from_secs(seconds.unsigned_abs());
+        if seconds < 0 {
+            UNIX_EPOCH.checked_sub(duration)
+        } else {
+            UNIX_EPOCH.checked_add(duration)
+        }
+    }
+
     pub fn get_date(self) -> DateTuple {
         self.d
     }
@@ -33,13 +128,742 @@ impl DateTimeTuple {
         self.t
     }
 
+    /// Gets the day of the week on which the date falls.
+    pub fn weekday(self) -> Weekday {
+        self.d.weekday()
+    }
+
+    /// Returns the minimum date and time handled - 1st January 0000 at 00:00:00.
+    pub fn min_value() -> DateTimeTuple {
+        DateTimeTuple::new(DateTuple::min_value(), TimeTuple::new(0, 0, 0))
+    }
+
+    /// Returns the maximum date and time handled - 31st December 9999 at 23:59:59.
+    pub fn max_value() -> DateTimeTuple {
+        DateTimeTuple::new(DateTuple::max_value(), TimeTuple::new(23, 59, 59))
+    }
+
+    /// Adds a number of seconds to a DateTimeTuple, carrying into the date
+    /// past midnight. Will not go past `DateTimeTuple::max_value()`.
+    pub fn add_seconds(&mut self, seconds: u32) {
+        *self = DateTimeTuple::from_seconds(self.to_seconds().saturating_add(u64::from(seconds)));
+    }
+
+    /// Subtracts a number of seconds from a DateTimeTuple, carrying into the date
+    /// past midnight. Will not go past `DateTimeTuple::min_value()`.
+    pub fn subtract_seconds(&mut self, seconds: u32) {
+        *self = DateTimeTuple::from_seconds(self.to_seconds().saturating_sub(u64::from(seconds)));
+    }
+
+    /// Adds a number of minutes to a DateTimeTuple, carrying into the date
+    /// past midnight. Will not go past `DateTimeTuple::max_value()`.
+    pub fn add_minutes(&mut self, minutes: u32) {
+        *self =
+            DateTimeTuple::from_seconds(self.to_seconds().saturating_add(60 * u64::from(minutes)));
+    }
+
+    /// Subtracts a number of minutes from a DateTimeTuple, carrying into the date
+    /// past midnight. Will not go past `DateTimeTuple::min_value()`.
+    pub fn subtract_minutes(&mut self, minutes: u32) {
+        *self =
+            DateTimeTuple::from_seconds(self.to_seconds().saturating_sub(60 * u64::from(minutes)));
+    }
+
+    /// Adds a number of hours to a DateTimeTuple, carrying into the date
+    /// past midnight. Will not go past `DateTimeTuple::max_value()`.
+    pub fn add_hours(&mut self, hours: u32) {
+        *self =
+            DateTimeTuple::from_seconds(self.to_seconds().saturating_add(3600 * u64::from(hours)));
+    }
+
+    /// Subtracts a number of hours from a DateTimeTuple, carrying into the date
+    /// past midnight. Will not go past `DateTimeTuple::min_value()`.
+    pub fn subtract_hours(&mut self, hours: u32) {
+        *self =
+            DateTimeTuple::from_seconds(self.to_seconds().saturating_sub(3600 * u64::from(hours)));
+    }
+
+    /// Adds a number of days to a DateTimeTuple, leaving the time unchanged.
+    ///
+    /// Will not go past Dec 9999, as with `DateTuple::add_days()`.
+    pub fn add_days(&mut self, days: u32) {
+        self.d.add_days(days);
+    }
+
+    /// Subtracts a number of days from a DateTimeTuple, leaving the time unchanged.
+    ///
+    /// Will not go past 1 Jan 0000, as with `DateTuple::subtract_days()`.
+    pub fn subtract_days(&mut self, days: u32) {
+        self.d.subtract_days(days);
+    }
+
+    /// Adds a number of months to a DateTimeTuple, leaving the time unchanged.
+    ///
+    /// The day of month is clamped in the same way as `DateTuple::add_months()`.
+    pub fn add_months(&mut self, months: u32) {
+        self.d.add_months(months);
+    }
+
+    /// Subtracts a number of months from a DateTimeTuple, leaving the time unchanged.
+    ///
+    /// The day of month is clamped in the same way as `DateTuple::subtract_months()`.
+    pub fn subtract_months(&mut self, months: u32) {
+        self.d.subtract_months(months);
+    }
+
+    /// Adds a number of years to a DateTimeTuple, leaving the time unchanged.
+    ///
+    /// Feb 29 is handled in the same way as `DateTuple::add_years()`.
+    pub fn add_years(&mut self, years: u16) {
+        self.d.add_years(years);
+    }
+
+    /// Subtracts a number of years from a DateTimeTuple, leaving the time unchanged.
+    ///
+    /// Feb 29 is handled in the same way as `DateTuple::subtract_years()`.
+    pub fn subtract_years(&mut self, years: u16) {
+        self.d.subtract_years(years);
+    }
+
+    /// Adds a number of seconds to a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_add_seconds(self, seconds: u32) -> Option<DateTimeTuple> {
+        DateTimeTuple::checked_from_seconds(self.to_seconds().checked_add(u64::from(seconds))?)
+    }
+
+    /// Subtracts a number of seconds from a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_sub_seconds(self, seconds: u32) -> Option<DateTimeTuple> {
+        DateTimeTuple::checked_from_seconds(self.to_seconds().checked_sub(u64::from(seconds))?)
+    }
+
+    /// Adds a number of minutes to a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_add_minutes(self, minutes: u32) -> Option<DateTimeTuple> {
+        DateTimeTuple::checked_from_seconds(self.to_seconds().checked_add(60 * u64::from(minutes))?)
+    }
+
+    /// Subtracts a number of minutes from a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_sub_minutes(self, minutes: u32) -> Option<DateTimeTuple> {
+        DateTimeTuple::checked_from_seconds(self.to_seconds().checked_sub(60 * u64::from(minutes))?)
+    }
+
+    /// Adds a number of hours to a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_add_hours(self, hours: u32) -> Option<DateTimeTuple> {
+        DateTimeTuple::checked_from_seconds(self.to_seconds().checked_add(3600 * u64::from(hours))?)
+    }
+
+    /// Subtracts a number of hours from a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_sub_hours(self, hours: u32) -> Option<DateTimeTuple> {
+        DateTimeTuple::checked_from_seconds(self.to_seconds().checked_sub(3600 * u64::from(hours))?)
+    }
+
+    /// Adds a number of days to a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_add_days(self, days: u32) -> Option<DateTimeTuple> {
+        self.d
+            .checked_add_days(days)
+            .map(|d| DateTimeTuple::new(d, self.t))
+    }
+
+    /// Subtracts a number of days from a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_sub_days(self, days: u32) -> Option<DateTimeTuple> {
+        self.d
+            .checked_sub_days(days)
+            .map(|d| DateTimeTuple::new(d, self.t))
+    }
+
+    /// Adds a number of months to a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_add_months(self, months: u32) -> Option<DateTimeTuple> {
+        self.d
+            .checked_add_months(months)
+            .map(|d| DateTimeTuple::new(d, self.t))
+    }
+
+    /// Subtracts a number of months from a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_sub_months(self, months: u32) -> Option<DateTimeTuple> {
+        self.d
+            .checked_sub_months(months)
+            .map(|d| DateTimeTuple::new(d, self.t))
+    }
+
+    /// Adds a number of years to a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_add_years(self, years: u16) -> Option<DateTimeTuple> {
+        self.d
+            .checked_add_years(years)
+            .map(|d| DateTimeTuple::new(d, self.t))
+    }
+
+    /// Subtracts a number of years from a DateTimeTuple, returning `None` if the
+    /// result would fall outside of the range the DateTimeTuple can hold.
+    pub fn checked_sub_years(self, years: u16) -> Option<DateTimeTuple> {
+        self.d
+            .checked_sub_years(years)
+            .map(|d| DateTimeTuple::new(d, self.t))
+    }
+
+    /// Adds a number of seconds to a DateTimeTuple, stopping at `DateTimeTuple::max_value()`.
+    ///
+    /// The same as `add_seconds()`, but produces a new DateTimeTuple.
+    pub fn saturating_add_seconds(self, seconds: u32) -> DateTimeTuple {
+        let mut result = self;
+        result.add_seconds(seconds);
+        result
+    }
+
+    /// Subtracts a number of seconds from a DateTimeTuple, stopping at `DateTimeTuple::min_value()`.
+    ///
+    /// The same as `subtract_seconds()`, but produces a new DateTimeTuple.
+    pub fn saturating_sub_seconds(self, seconds: u32) -> DateTimeTuple {
+        let mut result = self;
+        result.subtract_seconds(seconds);
+        result
+    }
+
+    /// Adds a number of minutes to a DateTimeTuple, stopping at `DateTimeTuple::max_value()`.
+    ///
+    /// The same as `add_minutes()`, but produces a new DateTimeTuple.
+    pub fn saturating_add_minutes(self, minutes: u32) -> DateTimeTuple {
+        let mut result = self;
+        result.add_minutes(minutes);
+        result
+    }
+
+    /// Subtracts a number of minutes from a DateTimeTuple, stopping at `DateTimeTuple::min_value()`.
+    ///
+    /// The same as `subtract_minutes()`, but produces a new DateTimeTuple.
+    pub fn saturating_sub_minutes(self, minutes: u32) -> DateTimeTuple {
+        let mut result = self;
+        result.subtract_minutes(minutes);
+        result
+    }
+
+    /// Adds a number of hours to a DateTimeTuple, stopping at `DateTimeTuple::max_value()`.
+    ///
+    /// The same as `add_hours()`, but produces a new DateTimeTuple.
+    pub fn saturating_add_hours(self, hours: u32) -> DateTimeTuple {
+        let mut result = self;
+        result.add_hours(hours);
+        result
+    }
+
+    /// Subtracts a number of hours from a DateTimeTuple, stopping at `DateTimeTuple::min_value()`.
+    ///
+    /// The same as `subtract_hours()`, but produces a new DateTimeTuple.
+    pub fn saturating_sub_hours(self, hours: u32) -> DateTimeTuple {
+        let mut result = self;
+        result.subtract_hours(hours);
+        result
+    }
+
+    /// Adds a number of days to a DateTimeTuple, stopping at `DateTimeTuple::max_value()`.
+    ///
+    /// Unlike `add_days()`, the time is also clamped if the date would go out of range.
+    pub fn saturating_add_days(self, days: u32) -> DateTimeTuple {
+        self.checked_add_days(days)
+            .unwrap_or_else(DateTimeTuple::max_value)
+    }
+
+    /// Subtracts a number of days from a DateTimeTuple, stopping at `DateTimeTuple::min_value()`.
+    ///
+    /// Unlike `subtract_days()`, the time is also clamped if the date would go out of range.
+    pub fn saturating_sub_days(self, days: u32) -> DateTimeTuple {
+        self.checked_sub_days(days)
+            .unwrap_or_else(DateTimeTuple::min_value)
+    }
+
+    /// Adds a number of months to a DateTimeTuple, stopping at `DateTimeTuple::max_value()`.
+    ///
+    /// Unlike `add_months()`, the time is also clamped if the date would go out of range.
+    pub fn saturating_add_months(self, months: u32) -> DateTimeTuple {
+        self.checked_add_months(months)
+            .unwrap_or_else(DateTimeTuple::max_value)
+    }
+
+    /// Subtracts a number of months from a DateTimeTuple, stopping at `DateTimeTuple::min_value()`.
+    ///
+    /// Unlike `subtract_months()`, the time is also clamped if the date would go out of range.
+    pub fn saturating_sub_months(self, months: u32) -> DateTimeTuple {
+        self.checked_sub_months(months)
+            .unwrap_or_else(DateTimeTuple::min_value)
+    }
+
+    /// Adds a number of years to a DateTimeTuple, stopping at `DateTimeTuple::max_value()`.
+    ///
+    /// Unlike `add_years()`, the time is also clamped if the date would go out of range.
+    pub fn saturating_add_years(self, years: u16) -> DateTimeTuple {
+        self.checked_add_years(years)
+            .unwrap_or_else(DateTimeTuple::max_value)
+    }
+
+    /// Subtracts a number of years from a DateTimeTuple, stopping at `DateTimeTuple::min_value()`.
+    ///
+    /// Unlike `subtract_years()`, the time is also clamped if the date would go out of range.
+    pub fn saturating_sub_years(self, years: u16) -> DateTimeTuple {
+        self.checked_sub_years(years)
+            .unwrap_or_else(DateTimeTuple::min_value)
+    }
+
+    /// Gets the `SignedDuration` from `other` to this DateTimeTuple, which is
+    /// negative if this DateTimeTuple is the earlier of the two.
+    pub fn signed_duration_since(self, other: DateTimeTuple) -> SignedDuration {
+        SignedDuration::between(other, self)
+    }
+
+    /// Describes this DateTimeTuple relative to `other`, such as "3 hours ago",
+    /// "yesterday", or "in 2 weeks", using the default `RelativeFormatter`.
+    ///
+    /// ```
+    /// # use date_time::date_time_tuple::DateTimeTuple;
+    /// let now = DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z").unwrap();
+    /// let then = DateTimeTuple::from_rfc3339("2018-10-02T05:15:00Z").unwrap();
+    /// assert_eq!("3 hours ago", then.humanize_relative_to(now));
+    /// assert_eq!("in 3 hours", now.humanize_relative_to(then));
+    /// ```
+    pub fn humanize_relative_to(self, other: DateTimeTuple) -> String {
+        RelativeFormatter::new().humanize_date_time(self, other)
+    }
+
+    /// Gets the total number of seconds since `DateTimeTuple::min_value()`.
+    pub(crate) fn to_seconds(self) -> u64 {
+        u64::from(self.d.to_days() - 1) * SECONDS_IN_A_DAY + u64::from(self.t.to_seconds())
+    }
+
+    /// The inverse of `to_seconds()`, returning `None` beyond `DateTimeTuple::max_value()`.
+    fn checked_from_seconds(seconds: u64) -> Option<DateTimeTuple> {
+        if seconds > DateTimeTuple::max_value().to_seconds() {
+            None
+        } else {
+            Some(DateTimeTuple::from_seconds(seconds))
+        }
+    }
+
+    /// Moves forwards or backwards by a number of seconds, giving `None` if the result
+    /// would be outside of the supported range.
+    pub(crate) fn checked_add_signed_seconds(self, seconds: i64) -> Option<DateTimeTuple> {
+        let seconds = (self.to_seconds() as i64).checked_add(seconds)?;
+        if seconds < 0 {
+            None
+        } else {
+            DateTimeTuple::checked_from_seconds(seconds as u64)
+        }
+    }
+
+    /// Gets the number of seconds since the Unix epoch, 1970-01-01 00:00:00, which is
+    /// negative before it.
+    pub(crate) fn to_unix_seconds(self) -> i64 {
+        self.to_seconds() as i64 - UNIX_EPOCH_SECONDS
+    }
+
+    /// The inverse of `to_unix_seconds()`, returning `None` outside of the supported range.
+    pub(crate) fn checked_from_unix_seconds(seconds: i64) -> Option<DateTimeTuple> {
+        DateTimeTuple::min_value()
+            .checked_add_signed_seconds(seconds.checked_add(UNIX_EPOCH_SECONDS)?)
+    }
+
+    /// The inverse of `to_seconds()`, clamping to `DateTimeTuple::max_value()`.
+    pub(crate) fn from_seconds(seconds: u64) -> DateTimeTuple {
+        let seconds = seconds.min(DateTimeTuple::max_value().to_seconds());
+        DateTimeTuple::new(
+            DateTuple::from_days((seconds / SECONDS_IN_A_DAY) as u32 + 1).unwrap(),
+            TimeTuple::from_seconds(seconds % SECONDS_IN_A_DAY),
+        )
+    }
+
     /// Produces a readable date and time.
     ///
     /// ## Examples
     /// * 2 Oct 2018 08:30:00
     /// * 13 Jan 2019 11:00:10
     pub fn to_readable_string(self) -> String {
-        format!("{} {}", self.d.to_readable_string(), self.t.to_string())
+        format!("{} {}", self.d.to_readable_string(), self.t)
+    }
+
+    /// Produces a readable date and time in the given locale,
+    /// following its date order for the date.
+    ///
+    /// ## Examples
+    /// * 2 oct. 2018 08:30:00 (`Locale::FR`)
+    /// * Oct 2, 2018 08:30:00 (`Locale::EN_US`)
+    pub fn to_readable_string_in(self, locale: &Locale) -> String {
+        format!("{} {}", self.d.to_readable_string_in(locale), self.t)
+    }
+
+    /// Produces an RFC 3339 timestamp, treating the DateTimeTuple as UTC.
+    ///
+    /// Formatted like 2018-10-02T08:30:00Z
+    pub fn to_rfc3339(self) -> String {
+        format!("{}T{}Z", self.d, self.t)
+    }
+
+    /// Produces an ISO 8601 timestamp in the basic format, treating the DateTimeTuple as UTC.
+    ///
+    /// Formatted like 20181002T083000Z
+    pub fn to_iso8601_basic(self) -> String {
+        format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+            self.d.get_year(),
+            self.d.get_month(),
+            self.d.get_date(),
+            self.t.get_hours(),
+            self.t.get_minutes(),
+            self.t.get_seconds()
+        )
+    }
+
+    /// Parses an RFC 3339 timestamp such as 2018-10-02T08:30:00Z, converting it to UTC.
+    ///
+    /// The date and time may be separated by `T` or a space, and the offset may be `Z`
+    /// or a value such as `+05:30`. Fractional seconds are truncated, and a leap second
+    /// is read as the 59th second.
+    ///
+    /// Fails with `Error::OutOfRange` if the time in UTC is before
+    /// `DateTimeTuple::min_value()` or after `DateTimeTuple::max_value()`.
+    pub fn from_rfc3339(s: &str) -> Result<DateTimeTuple, Error> {
+        let (date_time, offset) = DateTimeTuple::from_rfc3339_with_offset(s)?;
+        date_time.to_utc(offset, s)
+    }
+
+    /// Parses an RFC 3339 timestamp in the same way as `from_rfc3339()`, but without
+    /// converting it to UTC.
+    ///
+    /// Gives the date and time as written, along with its offset from UTC in seconds.
+    pub fn from_rfc3339_with_offset(s: &str) -> Result<(DateTimeTuple, i32), Error> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex = Regex::new(
+                r"^(\d{4})-(\d{2})-(\d{2})[Tt ](\d{2}):(\d{2}):(\d{2})(?:\.\d+)?(?:[Zz]|([+-])(\d{2}):(\d{2}))$"
+            )
+            .unwrap();
+        }
+
+        DateTimeTuple::from_timestamp_captures(s, VALID_FORMAT.captures(s), "2018-10-02T08:30:00Z")
+    }
+
+    /// Parses an ISO 8601 timestamp, converting it to UTC.
+    ///
+    /// Accepts everything `from_rfc3339()` does, as well as the basic format such as
+    /// 20181002T083000Z or 20181002T083000,5+0530.
+    pub fn from_iso8601(s: &str) -> Result<DateTimeTuple, Error> {
+        lazy_static! {
+            static ref BASIC_FORMAT: Regex = Regex::new(
+                r"^(\d{4})(\d{2})(\d{2})T(\d{2})(\d{2})(\d{2})(?:[.,]\d+)?(?:Z|([+-])(\d{2})(\d{2})?)$"
+            )
+            .unwrap();
+        }
+
+        let (date_time, offset) = match BASIC_FORMAT.captures(s) {
+            Some(caps) => {
+                DateTimeTuple::from_timestamp_captures(s, Some(caps), "20181002T083000Z")?
+            }
+            None => DateTimeTuple::from_rfc3339_with_offset(s)?,
+        };
+        date_time.to_utc(offset, s)
+    }
+
+    /// Builds a DateTimeTuple and offset from the captures of an RFC 3339 or
+    /// ISO 8601 timestamp, which must both number their groups the same way.
+    fn from_timestamp_captures(
+        s: &str,
+        caps: Option<Captures>,
+        expected: &'static str,
+    ) -> Result<(DateTimeTuple, i32), Error> {
+        let invalid = || Error::InvalidFormat {
+            type_name: TypeName::DateTimeTuple,
+            input: s.to_string(),
+            expected,
+        };
+        let caps = caps.ok_or_else(invalid)?;
+        let number = |i: usize| {
+            caps.get(i)
+                .map_or(0, |m| u32::from_str(m.as_str()).unwrap())
+        };
+        let date = DateTuple::new(number(1) as u16, number(2) as u8, number(3) as u8)?;
+        let (hours, minutes, seconds) = (number(4), number(5), number(6));
+        let (offset_hours, offset_minutes) = (number(8), number(9));
+        if hours > 23 || minutes > 59 || seconds > 60 || offset_hours > 23 || offset_minutes > 59 {
+            return Err(invalid());
+        }
+        let time = TimeTuple::new(hours as i32, minutes as i32, seconds.min(59) as i32);
+        let offset = (offset_hours * 3600 + offset_minutes * 60) as i32;
+        let offset = match caps.get(7) {
+            Some(sign) if sign.as_str() == "-" => -offset,
+            _ => offset,
+        };
+        Ok((DateTimeTuple::new(date, time), offset))
+    }
+
+    /// Produces an RFC 2822 date, as used in email headers, treating the DateTimeTuple as UTC.
+    ///
+    /// Formatted like Tue, 2 Oct 2018 08:30:00 +0000
+    pub fn to_rfc2822(self) -> String {
+        format!(
+            "{}, {} {} {:04} {} +0000",
+            self.weekday().to_short_string(),
+            self.d.get_date(),
+            MONTH_STRINGS[self.d.get_month() as usize - 1],
+            self.d.get_year(),
+            self.t
+        )
+    }
+
+    /// Parses an RFC 2822 date such as Tue, 2 Oct 2018 08:30:00 +0000, converting it to UTC.
+    ///
+    /// The weekday and seconds are optional, and the obsolete two- and three-digit
+    /// years and named zones such as `GMT` and `EST` are accepted. A weekday which
+    /// doesn't match the date results in `Error::ParseError`.
+    pub fn from_rfc2822(s: &str) -> Result<DateTimeTuple, Error> {
+        let (date_time, offset) = DateTimeTuple::from_rfc2822_with_offset(s)?;
+        date_time.to_utc(offset, s)
+    }
+
+    /// Parses an RFC 2822 date in the same way as `from_rfc2822()`, but without
+    /// converting it to UTC.
+    ///
+    /// Gives the date and time as written, along with its offset from UTC in seconds.
+    pub fn from_rfc2822_with_offset(s: &str) -> Result<(DateTimeTuple, i32), Error> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex = Regex::new(
+                r"^\s*(?:(?P<weekday>[A-Za-z]{3})\s*,\s*)?(?P<day>\d{1,2})\s+(?P<month>[A-Za-z]{3})\s+(?P<year>\d{2,4})\s+(?P<hour>\d{2}):(?P<minute>\d{2})(?::(?P<second>\d{2}))?\s+(?P<zone>[+-]\d{4}|[A-Za-z]{1,3})\s*$"
+            )
+            .unwrap();
+        }
+
+        // Obsolete two-digit years below 50 are in the 2000s, and all other
+        // two- and three-digit years are counted from 1900.
+        DateTimeTuple::from_mail_captures(
+            s,
+            VALID_FORMAT.captures(s),
+            "Tue, 2 Oct 2018 08:30:00 +0000",
+            |year, digits| match digits {
+                2 if year < 50 => year + 2000,
+                2 | 3 => year + 1900,
+                _ => year,
+            },
+        )
+    }
+
+    /// Produces an HTTP date in the IMF-fixdate format preferred by RFC 7231.
+    ///
+    /// Formatted like Tue, 02 Oct 2018 08:30:00 GMT
+    pub fn to_http_date(self) -> String {
+        format!(
+            "{}, {:02} {} {:04} {} GMT",
+            self.weekday().to_short_string(),
+            self.d.get_date(),
+            MONTH_STRINGS[self.d.get_month() as usize - 1],
+            self.d.get_year(),
+            self.t
+        )
+    }
+
+    /// Parses an HTTP date such as those in `Date`, `Last-Modified`, and `Expires` headers.
+    ///
+    /// Accepts the IMF-fixdate format (Tue, 02 Oct 2018 08:30:00 GMT) as well as the
+    /// obsolete RFC 850 (Tuesday, 02-Oct-18 08:30:00 GMT) and asctime
+    /// (Tue Oct  2 08:30:00 2018) formats. An RFC 850 year which would be more than 50 years
+    /// in the future is taken to be in the previous century.
+    ///
+    /// A weekday which doesn't match the date results in `Error::ParseError`.
+    pub fn from_http_date(s: &str) -> Result<DateTimeTuple, Error> {
+        DateTimeTuple::from_http_date_with_clock(s, &SystemClock)
+    }
+
+    /// Same as `DateTimeTuple::from_http_date()` but takes the current year, which RFC 850
+    /// years are expanded around, from a clock. The clock is only read for RFC 850 dates.
+    pub fn from_http_date_with_clock<C: Clock + ?Sized>(
+        s: &str,
+        clock: &C,
+    ) -> Result<DateTimeTuple, Error> {
+        lazy_static! {
+            static ref IMF_FIXDATE: Regex = Regex::new(
+                r"^(?P<weekday>[A-Za-z]{3}), (?P<day>\d{2}) (?P<month>[A-Za-z]{3}) (?P<year>\d{4}) (?P<hour>\d{2}):(?P<minute>\d{2}):(?P<second>\d{2}) GMT$"
+            )
+            .unwrap();
+            static ref RFC_850: Regex = Regex::new(
+                r"^(?P<weekday>[A-Za-z]{6,9}), (?P<day>\d{2})-(?P<month>[A-Za-z]{3})-(?P<year>\d{2}) (?P<hour>\d{2}):(?P<minute>\d{2}):(?P<second>\d{2}) GMT$"
+            )
+            .unwrap();
+            static ref ASCTIME: Regex = Regex::new(
+                r"^(?P<weekday>[A-Za-z]{3}) (?P<month>[A-Za-z]{3}) (?P<day> \d|\d{2}) (?P<hour>\d{2}):(?P<minute>\d{2}):(?P<second>\d{2}) (?P<year>\d{4})$"
+            )
+            .unwrap();
+        }
+
+        let caps = IMF_FIXDATE
+            .captures(s)
+            .or_else(|| RFC_850.captures(s))
+            .or_else(|| ASCTIME.captures(s));
+        let (date_time, _) = DateTimeTuple::from_mail_captures(
+            s,
+            caps,
+            "Tue, 02 Oct 2018 08:30:00 GMT",
+            |year, digits| {
+                if digits != 2 {
+                    return year;
+                }
+                let this_year = DateTuple::today_with_clock(clock).get_year();
+                let year = this_year - this_year % 100 + year;
+                if year > this_year + 50 {
+                    year - 100
+                } else {
+                    year
+                }
+            },
+        )?;
+        Ok(date_time)
+    }
+
+    /// Builds a DateTimeTuple and offset from the named captures of an RFC 2822 or HTTP date,
+    /// checking that the weekday, if given, matches the date.
+    ///
+    /// `expand_year` is given the year and the number of digits it was written with.
+    fn from_mail_captures<F: Fn(u16, usize) -> u16>(
+        s: &str,
+        caps: Option<Captures>,
+        expected: &'static str,
+        expand_year: F,
+    ) -> Result<(DateTimeTuple, i32), Error> {
+        let invalid = || Error::InvalidFormat {
+            type_name: TypeName::DateTimeTuple,
+            input: s.to_string(),
+            expected,
+        };
+        let caps = caps.ok_or_else(invalid)?;
+        let number = |name: &str| {
+            caps.name(name)
+                .map_or(0, |m| u16::from_str(m.as_str().trim_start()).unwrap())
+        };
+        let month = MONTH_STRINGS
+            .iter()
+            .position(|m| m.eq_ignore_ascii_case(&caps["month"]))
+            .ok_or_else(invalid)?;
+        let year = expand_year(number("year"), caps["year"].len());
+        let date = DateTuple::new(year, month as u8 + 1, number("day") as u8)?;
+        let (hours, minutes, seconds) = (number("hour"), number("minute"), number("second"));
+        if hours > 23 || minutes > 59 || seconds > 60 {
+            return Err(invalid());
+        }
+        let time = TimeTuple::new(hours as i32, minutes as i32, seconds.min(59) as i32);
+
+        if let Some(weekday) = caps.name("weekday") {
+            if Weekday::from_str(weekday.as_str()).map_err(|_| invalid())? != date.weekday() {
+                return Err(Error::ParseError {
+                    input: s.to_string(),
+                    position: weekday.start(),
+                    expected: format!("{} to agree with the rest of the input", date.weekday()),
+                });
+            }
+        }
+
+        let offset = match caps.name("zone").map(|m| m.as_str()) {
+            None => 0,
+            Some(zone) if zone.starts_with('+') || zone.starts_with('-') => {
+                let hours = i32::from_str(&zone[1..3]).unwrap();
+                let minutes = i32::from_str(&zone[3..]).unwrap();
+                if minutes > 59 {
+                    return Err(invalid());
+                }
+                let offset = hours * 3600 + minutes * 60;
+                if zone.starts_with('-') {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            Some(zone) => {
+                3600 * match zone.to_ascii_uppercase().as_str() {
+                    "UT" | "GMT" | "Z" => 0,
+                    "EDT" => -4,
+                    "EST" | "CDT" => -5,
+                    "CST" | "MDT" => -6,
+                    "MST" | "PDT" => -7,
+                    "PST" => -8,
+                    _ => return Err(invalid()),
+                }
+            }
+        };
+        Ok((DateTimeTuple::new(date, time), offset))
+    }
+
+    /// Converts a date and time at the given offset from UTC to UTC.
+    fn to_utc(self, offset: i32, s: &str) -> Result<DateTimeTuple, Error> {
+        self.checked_add_signed_seconds(-i64::from(offset))
+            .ok_or_else(|| Error::OutOfRange {
+                type_name: TypeName::DateTimeTuple,
+                value: s.to_string(),
+                min: DateTimeTuple::min_value().to_rfc3339(),
+                max: DateTimeTuple::max_value().to_rfc3339(),
+            })
+    }
+
+    /// Formats the date and time using a strftime-like pattern; `"%a %-d %b %Y %H:%M"` produces `Tue 2 Oct 2018 20:30`.
+    ///
+    /// See the `format` module for the available directives.
+    pub fn format(self, pattern: &str) -> Result<String, Error> {
+        self.format_with(&Pattern::new(pattern)?)
+    }
+
+    /// Formats the date and time using a pattern compiled in advance with `Pattern::new()`.
+    pub fn format_with(self, pattern: &Pattern) -> Result<String, Error> {
+        pattern.format_value(Value::DateTime(self))
+    }
+
+    /// Parses a date and time from a string using a strftime-like pattern, such as `"%b %-d, %Y %-I:%M %p"`
+    /// for `Oct 2, 2018 8:30 PM`.
+    ///
+    /// See the `format` module for the available directives. If the string doesn't match
+    /// the pattern, `Error::ParseError` gives the position at which it stopped matching.
+    pub fn parse_from_str(input: &str, pattern: &str) -> Result<DateTimeTuple, Error> {
+        DateTimeTuple::parse_with(input, &Pattern::new(pattern)?)
+    }
+
+    /// Parses a date and time using a pattern compiled in advance with `Pattern::new()`.
+    pub fn parse_with(input: &str, pattern: &Pattern) -> Result<DateTimeTuple, Error> {
+        parse::parse(input, pattern, Kind::DateTime)?.to_date_time()
+    }
+
+    /// Resolves a natural-language expression relative to `reference`, such as
+    /// "tomorrow at 5pm", "next Friday", "in 2 hours", or "end of month".
+    ///
+    /// The following are understood, ignoring case:
+    ///
+    /// * "now", "today", "tomorrow", "yesterday", "the day after tomorrow",
+    ///   and "the day before yesterday".
+    /// * Offsets such as "in 3 days", "2 weeks ago", "an hour from now", or
+    ///   "5 minutes later", in seconds, minutes, hours, days, weeks, months, or years.
+    /// * Weekdays such as "Friday" or "this Friday" (on or after the reference date),
+    ///   "next Friday" (after it), and "last Friday" (before it).
+    /// * "next week", "last month", "this year", and so on, moving by one of that unit.
+    /// * "start of", "end of", "first day of", and "last day of" followed by a
+    ///   week (Monday to Sunday), month, or year, such as "end of next month" or
+    ///   "last day of February".
+    /// * Dates such as "March 5th", "5 March 2019", or "the 5th of March".
+    ///
+    /// Month names and dates given without a year are the next such month or date,
+    /// counting the current one. Any of these may be followed by a time of day such as
+    /// "at 5pm", "17:30", "noon", or "midnight", which can also be given first or alone;
+    /// otherwise the time of `reference` is kept.
+    ///
+    /// Unrecognised input gives `Error::ParseError`, and results beyond the supported
+    /// range give `Error::OutOfRange`.
+    ///
+    /// ```
+    /// # use date_time::date_time_tuple::DateTimeTuple;
+    /// let now = DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z").unwrap();
+    /// assert_eq!(
+    ///     DateTimeTuple::from_rfc3339("2018-10-05T17:00:00Z").unwrap(),
+    ///     DateTimeTuple::parse_natural("next Friday at 5pm", now).unwrap()
+    /// );
+    /// ```
+    pub fn parse_natural(input: &str, reference: DateTimeTuple) -> Result<DateTimeTuple, Error> {
+        natural::parse(input, reference, true)
     }
 }
 
@@ -49,12 +873,12 @@ impl DateTimeTuple {
 /// Formatted like 2018-10-02@08:30:00
 impl fmt::Display for DateTimeTuple {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}@{}", self.d.to_string(), self.t.to_string())
+        write!(f, "{}@{}", self.d, self.t)
     }
 }
 
 impl FromStr for DateTimeTuple {
-    type Err = String;
+    type Err = Error;
 
     /// Expects a string formatted like one obtained by calling `DateTimeTuple.to_string()`
     fn from_str(s: &str) -> Result<DateTimeTuple, Self::Err> {
@@ -66,25 +890,27 @@ impl FromStr for DateTimeTuple {
 
         if VALID_FORMAT.is_match(s) || LEGACY_FORMAT.is_match(s) {
             let mut parts = s.split('@');
-            let date_part = match DateTuple::from_str(parts.next().unwrap()) {
-                Ok(d) => d,
-                Err(e) => return Err(format!("Invalid date passed to from_str: {}", e)),
-            };
+            let date_part = DateTuple::from_str(parts.next().unwrap()).map_err(|e| {
+                Error::InvalidParsedValue {
+                    type_name: TypeName::DateTimeTuple,
+                    error: Box::new(e),
+                }
+            })?;
             let time_part = TimeTuple::from_str(parts.next().unwrap()).unwrap();
             Ok(DateTimeTuple::new(date_part, time_part))
         } else {
-            Err(format!("Invalid str formatting of DateTimeTuple: {}\nExpects a string formatted like 2018-11-02@08:30:00", s))
+            Err(Error::InvalidFormat {
+                type_name: TypeName::DateTimeTuple,
+                input: s.to_string(),
+                expected: "2018-11-02@08:30:00",
+            })
         }
     }
 }
 
 impl PartialOrd for DateTimeTuple {
     fn partial_cmp(&self, other: &DateTimeTuple) -> Option<Ordering> {
-        if self.d == other.d {
-            self.t.partial_cmp(&other.t)
-        } else {
-            self.d.partial_cmp(&other.d)
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -98,3 +924,39 @@ impl Ord for DateTimeTuple {
         }
     }
 }
+
+impl Add<Duration> for DateTimeTuple {
+    type Output = DateTimeTuple;
+    fn add(self, other: Duration) -> DateTimeTuple {
+        DateTimeTuple::from_seconds(self.to_seconds().saturating_add(other.to_seconds()))
+    }
+}
+
+impl AddAssign<Duration> for DateTimeTuple {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Duration> for DateTimeTuple {
+    type Output = DateTimeTuple;
+    fn sub(self, other: Duration) -> DateTimeTuple {
+        DateTimeTuple::from_seconds(self.to_seconds().saturating_sub(other.to_seconds()))
+    }
+}
+
+impl SubAssign<Duration> for DateTimeTuple {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+/// Gets the `Duration` between two `DateTimeTuple`s using `Duration::between()`.
+///
+/// As a `Duration` cannot be negative, this is the same whichever is earlier.
+impl Sub for DateTimeTuple {
+    type Output = Duration;
+    fn sub(self, other: DateTimeTuple) -> Duration {
+        Duration::between(self, other)
+    }
+}
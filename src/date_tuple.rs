@@ -1,12 +1,28 @@
+use clock::Clock;
+use date_time_tuple::DateTimeTuple;
 use date_utils;
+use error::{Error, TypeName};
+use format::{Pattern, Value};
+use locale::{DateOrder, Locale};
 use month_tuple::MonthTuple;
+use natural;
+use parse::{self, Kind};
 use regex::Regex;
+use relative::RelativeFormatter;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
 use std::str::FromStr;
+use week_tuple::WeekTuple;
+use weekday::Weekday;
 
-const DAYS_IN_A_COMMON_YEAR: u32 = 365;
-const DAYS_IN_A_LEAP_YEAR: u32 = 366;
+/// The number of days in a 400-year cycle of the Gregorian calendar.
+const DAYS_IN_AN_ERA: u32 = 146_097;
+/// The day count of 0000-01-01 in the shifted, March-based calendar used by
+/// `from_days()`, less one so that `min_value()` is day 1.
+const DAYS_BEFORE_MIN_VALUE: u32 = 146_036;
+/// The day count of 1970-01-01, with `min_value()` being day 1.
+const UNIX_EPOCH_DAYS: i64 = 719_529;
 
 pub type Date = DateTuple;
 
@@ -28,29 +44,45 @@ impl DateTuple {
     /// Takes a year, month, and day and converts them into a DateTuple.
     ///
     /// Will not overlap - the date entered must be valid without further calculation.
-    pub fn new(y: u16, m: u8, d: u8) -> Result<DateTuple, String> {
+    pub fn new(y: u16, m: u8, d: u8) -> Result<DateTuple, Error> {
         if y > 9999 {
-            return Err(format!(
-                "Invalid year in DateTuple {:?}: year must be <= 9999.",
-                DateTuple { y, m, d }
-            ));
+            return Err(Error::InvalidYear {
+                type_name: TypeName::DateTuple,
+                value: format!("{:?}", DateTuple { y, m, d }),
+            });
         }
         if (1..=12).contains(&m) {
             if d == 0 || d > date_utils::get_last_date_in_month(m, y) {
-                return Err(format!(
-                    "Invalid date in DateTuple: {:?}",
-                    DateTuple { y, m, d }
-                ));
+                return Err(Error::InvalidDay {
+                    type_name: TypeName::DateTuple,
+                    value: format!("{:?}", DateTuple { y, m, d }),
+                });
             }
             Ok(DateTuple { y, m, d })
         } else {
-            Err(format!(
-                "Invalid month in DateTuple: {:?}\nMonth must be between 1 and 12; Note that months are ONE-BASED since version 2.0.0.",
-                DateTuple { y, m, d }
-            ))
+            Err(Error::InvalidMonth {
+                type_name: TypeName::DateTuple,
+                value: format!("{:?}", DateTuple { y, m, d }),
+            })
         }
     }
 
+    /// Takes a year and a day of that year and converts them into a DateTuple.
+    ///
+    /// The day must be between 1 and 365, or 366 in a leap year.
+    pub fn from_ordinal(y: u16, day: u16) -> Result<DateTuple, Error> {
+        let days_in_year = if date_utils::is_leap_year(i64::from(y)) {
+            366
+        } else {
+            365
+        };
+        if day == 0 || day > days_in_year {
+            return Err(Error::InvalidDayOfYear { year: y, day });
+        }
+        let jan_1 = DateTuple::new(y, 1, 1)?;
+        DateTuple::from_days(jan_1.to_days() + u32::from(day) - 1)
+    }
+
     /// Returns the minimum date handled - 1st January 0000.
     pub fn min_value() -> DateTuple {
         DateTuple::new(0, 1, 1).unwrap()
@@ -61,11 +93,27 @@ impl DateTuple {
         DateTuple::new(9999, 12, 31).unwrap()
     }
 
-    /// Returns a `DateTuple` of the current date according to the system clock.
+    /// Returns a `DateTuple` of the current date in UTC according to the system clock.
     pub fn today() -> DateTuple {
         date_utils::now_as_datetuple()
     }
 
+    /// Returns a `DateTuple` of the current date in UTC according to a clock.
+    pub fn today_with_clock<C: Clock + ?Sized>(clock: &C) -> DateTuple {
+        clock.now().get_date()
+    }
+
+    /// Returns a `DateTuple` of the current date in the local time zone, as found by
+    /// `TimeZone::local()`.
+    pub fn today_local() -> DateTuple {
+        DateTimeTuple::now_local().get_date()
+    }
+
+    /// Returns a `DateTuple` of the current date in the local time zone according to a clock.
+    pub fn today_local_with_clock<C: Clock + ?Sized>(clock: &C) -> DateTuple {
+        DateTimeTuple::now_local_with_clock(clock).get_date()
+    }
+
     pub fn get_year(self) -> u16 {
         self.y
     }
@@ -78,6 +126,22 @@ impl DateTuple {
         self.d
     }
 
+    /// Gets the day of the week on which the date falls.
+    pub fn weekday(self) -> Weekday {
+        // Day 1, 1 Jan 0000, was a Saturday.
+        Weekday::from_days_from_monday(self.to_days() + 4)
+    }
+
+    /// Gets the ordinal day of the year, with 1 Jan being 1.
+    pub fn day_of_year(self) -> u16 {
+        let jan_1 = DateTuple {
+            y: self.y,
+            m: 1,
+            d: 1,
+        };
+        (self.to_days() - jan_1.to_days() + 1) as u16
+    }
+
     /// Gets a DateTuple representing the date immediately following
     /// the current one. Will not go past Dec 9999.
     pub fn next_date(self) -> DateTuple {
@@ -137,17 +201,20 @@ impl DateTuple {
     }
 
     /// Adds a number of days to a DateTuple.
+    ///
+    /// Will not go past Dec 9999.
     pub fn add_days(&mut self, days: u32) {
-        for _ in 0..days {
-            *self = self.next_date();
-        }
+        let max_days = DateTuple::max_value().to_days();
+        let new_days = self.to_days().saturating_add(days).min(max_days);
+        *self = DateTuple::from_days(new_days).unwrap();
     }
 
     /// Subtracts a number of days from a DateTuple.
+    ///
+    /// Will not go past 1 Jan 0000.
     pub fn subtract_days(&mut self, days: u32) {
-        for _ in 0..days {
-            *self = self.previous_date();
-        }
+        let new_days = self.to_days().saturating_sub(days).max(1);
+        *self = DateTuple::from_days(new_days).unwrap();
     }
 
     /// Adds a number of months to a DateTuple.
@@ -157,13 +224,7 @@ impl DateTuple {
     pub fn add_months(&mut self, months: u32) {
         let mut new_month = MonthTuple::from(*self);
         new_month.add_months(months);
-        let last_date_in_month =
-            date_utils::get_last_date_in_month(new_month.get_month(), new_month.get_year());
-        if self.d > last_date_in_month {
-            self.d = last_date_in_month;
-        }
-        self.y = new_month.get_year();
-        self.m = new_month.get_month();
+        *self = self.with_month(new_month);
     }
 
     /// Subtracts a number of months from a DateTuple.
@@ -173,13 +234,7 @@ impl DateTuple {
     pub fn subtract_months(&mut self, months: u32) {
         let mut new_month = MonthTuple::from(*self);
         new_month.subtract_months(months);
-        let last_date_in_month =
-            date_utils::get_last_date_in_month(new_month.get_month(), new_month.get_year());
-        if self.d > last_date_in_month {
-            self.d = last_date_in_month;
-        }
-        self.y = new_month.get_year();
-        self.m = new_month.get_month();
+        *self = self.with_month(new_month);
     }
 
     /// Adds a number of years to a DateTuple.
@@ -187,14 +242,7 @@ impl DateTuple {
     /// If the date is set to Feb 29 and the resulting year is not a leap year,
     /// it will be changed to Feb 28.
     pub fn add_years(&mut self, years: u16) {
-        let mut new_years = self.y + years;
-        if new_years > 9999 {
-            new_years = 9999;
-        }
-        if self.m == 2 && self.d == 29 && !date_utils::is_leap_year(new_years) {
-            self.d = 28
-        }
-        self.y = new_years;
+        *self = self.with_year(self.y.saturating_add(years).min(9999));
     }
 
     /// Subtracts a number of years from a DateTuple.
@@ -202,15 +250,132 @@ impl DateTuple {
     /// If the date is set to Feb 29 and the resulting year is not a leap year,
     /// it will be changed to Feb 28.
     pub fn subtract_years(&mut self, years: u16) {
-        let mut new_years = i32::from(self.y) - i32::from(years);
-        if new_years < 0 {
-            new_years = 0;
+        *self = self.with_year(self.y.saturating_sub(years));
+    }
+
+    /// Adds a number of days to a DateTuple, returning `None` if the
+    /// result would fall outside of the range the DateTuple can hold.
+    pub fn checked_add_days(self, days: u32) -> Option<DateTuple> {
+        self.to_days()
+            .checked_add(days)
+            .and_then(|d| DateTuple::from_days(d).ok())
+    }
+
+    /// Subtracts a number of days from a DateTuple, returning `None` if the
+    /// result would fall outside of the range the DateTuple can hold.
+    pub fn checked_sub_days(self, days: u32) -> Option<DateTuple> {
+        self.to_days()
+            .checked_sub(days)
+            .and_then(|d| DateTuple::from_days(d).ok())
+    }
+
+    /// Adds a number of months to a DateTuple, returning `None` if the
+    /// result would fall outside of the range the DateTuple can hold.
+    pub fn checked_add_months(self, months: u32) -> Option<DateTuple> {
+        MonthTuple::from(self)
+            .checked_add_months(months)
+            .map(|m| self.with_month(m))
+    }
+
+    /// Subtracts a number of months from a DateTuple, returning `None` if the
+    /// result would fall outside of the range the DateTuple can hold.
+    pub fn checked_sub_months(self, months: u32) -> Option<DateTuple> {
+        MonthTuple::from(self)
+            .checked_sub_months(months)
+            .map(|m| self.with_month(m))
+    }
+
+    /// Adds a number of years to a DateTuple, returning `None` if the
+    /// result would fall outside of the range the DateTuple can hold.
+    pub fn checked_add_years(self, years: u16) -> Option<DateTuple> {
+        let new_years = u32::from(self.y) + u32::from(years);
+        if new_years > 9999 {
+            None
+        } else {
+            Some(self.with_year(new_years as u16))
         }
-        let new_years = new_years as u16;
-        if self.m == 2 && self.d == 29 && !date_utils::is_leap_year(new_years) {
-            self.d = 28
+    }
+
+    /// Subtracts a number of years from a DateTuple, returning `None` if the
+    /// result would fall outside of the range the DateTuple can hold.
+    pub fn checked_sub_years(self, years: u16) -> Option<DateTuple> {
+        self.y.checked_sub(years).map(|y| self.with_year(y))
+    }
+
+    /// Adds a number of days to a DateTuple, stopping at 31 Dec 9999.
+    ///
+    /// The same as `add_days()`, but produces a new DateTuple.
+    pub fn saturating_add_days(self, days: u32) -> DateTuple {
+        let mut result = self;
+        result.add_days(days);
+        result
+    }
+
+    /// Subtracts a number of days from a DateTuple, stopping at 1 Jan 0000.
+    ///
+    /// The same as `subtract_days()`, but produces a new DateTuple.
+    pub fn saturating_sub_days(self, days: u32) -> DateTuple {
+        let mut result = self;
+        result.subtract_days(days);
+        result
+    }
+
+    /// Adds a number of months to a DateTuple, stopping at 31 Dec 9999.
+    ///
+    /// The same as `add_months()`, but produces a new DateTuple.
+    pub fn saturating_add_months(self, months: u32) -> DateTuple {
+        let mut result = self;
+        result.add_months(months);
+        result
+    }
+
+    /// Subtracts a number of months from a DateTuple, stopping at 1 Jan 0000.
+    ///
+    /// The same as `subtract_months()`, but produces a new DateTuple.
+    pub fn saturating_sub_months(self, months: u32) -> DateTuple {
+        let mut result = self;
+        result.subtract_months(months);
+        result
+    }
+
+    /// Adds a number of years to a DateTuple, stopping at 31 Dec 9999.
+    ///
+    /// The same as `add_years()`, but produces a new DateTuple.
+    pub fn saturating_add_years(self, years: u16) -> DateTuple {
+        let mut result = self;
+        result.add_years(years);
+        result
+    }
+
+    /// Subtracts a number of years from a DateTuple, stopping at 1 Jan 0000.
+    ///
+    /// The same as `subtract_years()`, but produces a new DateTuple.
+    pub fn saturating_sub_years(self, years: u16) -> DateTuple {
+        let mut result = self;
+        result.subtract_years(years);
+        result
+    }
+
+    /// Moves the date into another month, keeping the day of month
+    /// unless it is beyond the last date in that month.
+    fn with_month(self, month: MonthTuple) -> DateTuple {
+        let last_date_in_month =
+            date_utils::get_last_date_in_month(month.get_month(), month.get_year());
+        DateTuple {
+            y: month.get_year(),
+            m: month.get_month(),
+            d: self.d.min(last_date_in_month),
+        }
+    }
+
+    /// Moves the date into another year, changing Feb 29 to Feb 28
+    /// if that year is not a leap year.
+    fn with_year(self, y: u16) -> DateTuple {
+        if self.m == 2 && self.d == 29 && !date_utils::is_leap_year(i64::from(y)) {
+            DateTuple { y, m: 2, d: 28 }
+        } else {
+            DateTuple { y, ..self }
         }
-        self.y = new_years;
     }
 
     /// Produces a readable date.
@@ -219,51 +384,126 @@ impl DateTuple {
     /// * 2 Oct 2018
     /// * 13 Jan 2019
     pub fn to_readable_string(self) -> String {
-        let month = MonthTuple::from(self);
-        format!("{} {}", self.d, month.to_readable_string())
+        self.to_readable_string_in(&Locale::EN)
+    }
+
+    /// Produces a readable date in the given locale, following its date order.
+    ///
+    /// ## Examples
+    /// * 2 oct. 2018 (`Locale::FR`)
+    /// * Oct 2, 2018 (`Locale::EN_US`)
+    /// * 2018 10月 2 (`Locale::JA`)
+    pub fn to_readable_string_in(self, locale: &Locale) -> String {
+        let month = locale.short_month_name(self.m);
+        match locale.get_date_order() {
+            DateOrder::DayMonthYear => format!("{} {} {:04}", self.d, month, self.y),
+            DateOrder::MonthDayYear => format!("{} {}, {:04}", month, self.d, self.y),
+            DateOrder::YearMonthDay => format!("{:04} {} {}", self.y, month, self.d),
+        }
+    }
+
+    /// Formats the date using a strftime-like pattern; `"%d/%m/%Y"` produces `02/10/2018`.
+    ///
+    /// See the `format` module for the available directives.
+    pub fn format(self, pattern: &str) -> Result<String, Error> {
+        self.format_with(&Pattern::new(pattern)?)
+    }
+
+    /// Formats the date using a pattern compiled in advance with `Pattern::new()`.
+    pub fn format_with(self, pattern: &Pattern) -> Result<String, Error> {
+        pattern.format_value(Value::Date(self))
+    }
+
+    /// Describes this DateTuple relative to `other`, such as "yesterday",
+    /// "in 3 days", or "last month", using the default `RelativeFormatter`.
+    ///
+    /// ```
+    /// # use date_time::date_tuple::DateTuple;
+    /// let today = DateTuple::new(2018, 10, 2).unwrap();
+    /// assert_eq!("yesterday", DateTuple::new(2018, 10, 1).unwrap().humanize_relative_to(today));
+    /// assert_eq!("in 3 weeks", DateTuple::new(2018, 10, 23).unwrap().humanize_relative_to(today));
+    /// ```
+    pub fn humanize_relative_to(self, other: DateTuple) -> String {
+        RelativeFormatter::new().humanize_date(self, other)
+    }
+
+    /// Parses a date from a string using a strftime-like pattern, such as `"%d/%m/%Y"`
+    /// for `02/10/2018`.
+    ///
+    /// See the `format` module for the available directives. If the string doesn't match
+    /// the pattern, `Error::ParseError` gives the position at which it stopped matching.
+    pub fn parse_from_str(input: &str, pattern: &str) -> Result<DateTuple, Error> {
+        DateTuple::parse_with(input, &Pattern::new(pattern)?)
+    }
+
+    /// Parses a date using a pattern compiled in advance with `Pattern::new()`.
+    pub fn parse_with(input: &str, pattern: &Pattern) -> Result<DateTuple, Error> {
+        parse::parse(input, pattern, Kind::Date)?.to_date()
+    }
+
+    /// Resolves a natural-language date relative to `reference`, such as "tomorrow",
+    /// "next Friday", "in 2 weeks", or "last day of February".
+    ///
+    /// Accepts the same expressions as `DateTimeTuple::parse_natural()`,
+    /// except for times of day and offsets shorter than a day.
+    ///
+    /// ```
+    /// # use date_time::date_time_tuple::DateTimeTuple;
+    /// # use date_time::date_tuple::DateTuple;
+    /// let now = DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z").unwrap();
+    /// assert_eq!(
+    ///     DateTuple::new(2019, 2, 28).unwrap(),
+    ///     DateTuple::parse_natural("last day of February", now).unwrap()
+    /// );
+    /// ```
+    pub fn parse_natural(input: &str, reference: DateTimeTuple) -> Result<DateTuple, Error> {
+        natural::parse(input, reference, false).map(DateTimeTuple::get_date)
+    }
+
+    /// Produces the ISO 8601 ordinal date, such as 2018-275 for 2 Oct 2018.
+    pub fn to_ordinal_string(self) -> String {
+        format!("{:04}-{:03}", self.y, self.day_of_year())
+    }
+
+    /// Produces the ISO 8601 week date, such as 2018-W40-2 for 2 Oct 2018.
+    ///
+    /// Fails for 1 and 2 Jan 0000, which fall in a week before 0000-W01.
+    pub fn to_week_date_string(self) -> Result<String, Error> {
+        let week = WeekTuple::try_from(self)?;
+        Ok(format!("{}-{}", week, self.weekday().number_from_monday()))
     }
 
     /// Gets the total number of days in the tuple,
     /// with the first being `DateTuple::min_value()`.
     pub fn to_days(self) -> u32 {
-        let mut total_days = 0u32;
-        for y in 0..self.y {
-            total_days += if date_utils::is_leap_year(y) {
-                DAYS_IN_A_LEAP_YEAR
-            } else {
-                DAYS_IN_A_COMMON_YEAR
-            }
-        }
-        for m in 1..self.m {
-            total_days += u32::from(date_utils::get_last_date_in_month(m, self.y));
-        }
-        total_days + u32::from(self.d)
+        (date_utils::days_from_civil(i64::from(self.y), self.m, self.d) + UNIX_EPOCH_DAYS) as u32
     }
 
     /// Calculates years, months, and days from a total number of
     /// days, with the first being `DateTuple::min_value()`.
-    pub fn from_days(mut total_days: u32) -> Result<DateTuple, String> {
-        let mut years = 0u16;
-        let mut months = 1u8;
-        while total_days
-            > if date_utils::is_leap_year(years) {
-                DAYS_IN_A_LEAP_YEAR
-            } else {
-                DAYS_IN_A_COMMON_YEAR
-            }
-        {
-            total_days -= if date_utils::is_leap_year(years) {
-                DAYS_IN_A_LEAP_YEAR
-            } else {
-                DAYS_IN_A_COMMON_YEAR
-            };
-            years += 1;
+    pub fn from_days(total_days: u32) -> Result<DateTuple, Error> {
+        let out_of_range = || Error::OutOfRange {
+            type_name: TypeName::DateTuple,
+            value: format!("{} days", total_days),
+            min: format!("{} days", DateTuple::min_value().to_days()),
+            max: format!("{} days", DateTuple::max_value().to_days()),
+        };
+        if total_days == 0 {
+            return Err(out_of_range());
         }
-        while total_days > u32::from(date_utils::get_last_date_in_month(months, years)) {
-            total_days -= u32::from(date_utils::get_last_date_in_month(months, years));
-            months += 1;
+        let z = u64::from(total_days) + u64::from(DAYS_BEFORE_MIN_VALUE);
+        let era = z / u64::from(DAYS_IN_AN_ERA);
+        let doe = z % u64::from(DAYS_IN_AN_ERA);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let d = doy - (153 * mp + 2) / 5 + 1;
+        let m = if mp < 10 { mp + 3 } else { mp - 9 };
+        let y = era * 400 + yoe + if m <= 2 { 1 } else { 0 } - 400;
+        if y > 9999 {
+            return Err(out_of_range());
         }
-        DateTuple::new(years, months, total_days as u8)
+        DateTuple::new(y as u16, m as u8, d as u8)
     }
 }
 
@@ -274,54 +514,68 @@ impl fmt::Display for DateTuple {
 }
 
 impl FromStr for DateTuple {
-    type Err = String;
+    type Err = Error;
 
     /// Expects a string formatted like 2018-11-02.
     ///
-    /// Also accepts the legacy crate format of 20181102.
+    /// Also accepts the legacy crate format of 20181102, ISO 8601
+    /// week dates such as 2018-W44-5 or 2018W445, and ISO 8601
+    /// ordinal dates such as 2018-306 or 2018306.
     fn from_str(s: &str) -> Result<DateTuple, Self::Err> {
         lazy_static! {
             static ref VALID_FORMAT: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
             static ref LEGACY_FORMAT: Regex = Regex::new(r"^\d{8}$").unwrap();
+            static ref WEEK_DATE_FORMAT: Regex =
+                Regex::new(r"^(\d{4})-W(\d{2})-(\d)$|^(\d{4})W(\d{2})(\d)$").unwrap();
+            static ref ORDINAL_FORMAT: Regex = Regex::new(r"^(\d{4})-?(\d{3})$").unwrap();
         }
 
-        if VALID_FORMAT.is_match(s) {
-            match DateTuple::new(
+        let date = if VALID_FORMAT.is_match(s) {
+            DateTuple::new(
                 u16::from_str(&s[0..4]).unwrap(),
                 u8::from_str(&s[5..7]).unwrap(),
                 u8::from_str(&s[8..10]).unwrap(),
-            ) {
-                Ok(d) => Ok(d),
-                Err(e) => Err(format!("Invalid date passed to from_str: {}", e)),
-            }
+            )
         } else if LEGACY_FORMAT.is_match(s) {
             let (s1, s2) = s.split_at(4);
             let (s2, s3) = s2.split_at(2);
-            match DateTuple::new(
+            DateTuple::new(
                 u16::from_str(s1).unwrap(),
                 u8::from_str(s2).unwrap(),
                 u8::from_str(s3).unwrap(),
-            ) {
-                Ok(d) => Ok(d),
-                Err(e) => Err(format!("Invalid date passed to from_str: {}", e)),
-            }
+            )
+        } else if let Some(caps) = WEEK_DATE_FORMAT.captures(s) {
+            let group = if caps.get(1).is_some() { 1 } else { 4 };
+            WeekTuple::new(
+                u16::from_str(&caps[group]).unwrap(),
+                u8::from_str(&caps[group + 1]).unwrap(),
+            )
+            .and_then(|w| {
+                Weekday::from_number_from_monday(u8::from_str(&caps[group + 2]).unwrap())
+                    .and_then(|d| w.to_date(d))
+            })
+        } else if let Some(caps) = ORDINAL_FORMAT.captures(s) {
+            DateTuple::from_ordinal(
+                u16::from_str(&caps[1]).unwrap(),
+                u16::from_str(&caps[2]).unwrap(),
+            )
         } else {
-            Err(format!("Invalid str formatting of DateTuple: {}\nExpects a string formatted like 2018-11-02.", s))
-        }
+            return Err(Error::InvalidFormat {
+                type_name: TypeName::DateTuple,
+                input: s.to_string(),
+                expected: "2018-11-02",
+            });
+        };
+        date.map_err(|e| Error::InvalidParsedValue {
+            type_name: TypeName::DateTuple,
+            error: Box::new(e),
+        })
     }
 }
 
 impl PartialOrd for DateTuple {
     fn partial_cmp(&self, other: &DateTuple) -> Option<Ordering> {
-        if self.y == other.y {
-            if self.m == other.m {
-                self.d.partial_cmp(&other.d)
-            } else {
-                self.m.partial_cmp(&other.m)
-            }
-        } else {
-            self.y.partial_cmp(&other.y)
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -1,3 +1,4 @@
+use date_time_tuple::DateTimeTuple;
 use date_tuple::DateTuple;
 use month_tuple::MonthTuple;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -9,16 +10,31 @@ lazy_static! {
     static ref UNIX_EPOCH_DATETUPLE: DateTuple = DateTuple::new(1970, 1, 1).unwrap();
 }
 
-/// Takes a year as a u16 and returns whether it is a leap year.
-pub fn is_leap_year(year: u16) -> bool {
+/// Takes a year and returns whether it is a leap year in the proleptic
+/// Gregorian calendar.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn is_leap_year(year: i64) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
+/// Gets the number of days from the Unix epoch to a date in the proleptic
+/// Gregorian calendar, which may be outside of the range of `DateTuple`.
+pub fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    // Counts from March so that leap days fall at the end of each year.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
 /// Produces the integer representing the last date in the month in year.
 pub fn get_last_date_in_month(month: u8, year: u16) -> u8 {
     match month {
         2 => {
-            if is_leap_year(year) {
+            if is_leap_year(i64::from(year)) {
                 29
             } else {
                 28
@@ -36,6 +52,27 @@ pub fn now_as_datetuple() -> DateTuple {
         .unwrap()
 }
 
+/// Gets the current date and time as a `DateTimeTuple`, reading the clock once
+/// for both so that the date and time always agree.
+///
+/// A clock set outside of the range of `DateTimeTuple` gives its minimum or
+/// maximum value rather than an error.
+pub fn now_as_datetimetuple() -> DateTimeTuple {
+    clamp_system_time(SystemTime::now())
+}
+
+/// Converts a `SystemTime` to a `DateTimeTuple`, giving the minimum or maximum
+/// value for times outside of its range.
+fn clamp_system_time(time: SystemTime) -> DateTimeTuple {
+    DateTimeTuple::from_system_time(time).unwrap_or_else(|_| {
+        if time < UNIX_EPOCH {
+            DateTimeTuple::min_value()
+        } else {
+            DateTimeTuple::max_value()
+        }
+    })
+}
+
 /// Gets the current month as a `MonthTuple`
 pub fn now_as_monthtuple() -> MonthTuple {
     MonthTuple::from(now_as_datetuple())
@@ -72,15 +109,45 @@ mod tests {
         assert!(!is_leap_year(2100));
         assert!(!is_leap_year(2018));
         assert!(!is_leap_year(2013));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(10_100));
+    }
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(-1, days_from_civil(1969, 12, 31));
+        assert_eq!(11_017, days_from_civil(2000, 3, 1));
+        assert_eq!(-719_529, days_from_civil(-1, 12, 31));
+        assert_eq!(
+            i64::from(DateTuple::max_value().to_days()) + 1,
+            days_from_civil(10_000, 1, 1) + 719_529
+        );
     }
 
     #[test]
     fn test_now_functions_do_not_panic() {
         now_as_datetuple();
+        now_as_datetimetuple();
         now_as_monthtuple();
         now_as_timetuple();
     }
 
+    #[test]
+    fn test_clamp_system_time() {
+        assert_eq!(
+            DateTimeTuple::new(*UNIX_EPOCH_DATETUPLE, TimeTuple::new(0, 0, 0)),
+            clamp_system_time(UNIX_EPOCH)
+        );
+        let ten_thousand_years = Duration::from_secs(10_000 * 366 * SECONDS_IN_A_DAY);
+        if let Some(time) = UNIX_EPOCH.checked_add(ten_thousand_years) {
+            assert_eq!(DateTimeTuple::max_value(), clamp_system_time(time));
+        }
+        if let Some(time) = UNIX_EPOCH.checked_sub(ten_thousand_years) {
+            assert_eq!(DateTimeTuple::min_value(), clamp_system_time(time));
+        }
+    }
+
     #[test]
     fn test_days_from_duration() {
         assert_eq!(0, extract_days_from_duration(0));
@@ -0,0 +1,266 @@
+use date_utils;
+use std::error;
+use std::fmt;
+
+/// The types of this crate which can be named in an `Error`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub enum TypeName {
+    DateTimeTuple,
+    DateTuple,
+    Duration,
+    MonthTuple,
+    OffsetDateTime,
+    QuarterTuple,
+    SignedDuration,
+    TimeTuple,
+    UtcOffset,
+    WeekTuple,
+    Weekday,
+    YearTuple,
+    ZonedDateTime,
+}
+
+impl TypeName {
+    /// Gets the word for a value of this type used in messages, such as "month".
+    fn value_noun(self) -> &'static str {
+        match self {
+            TypeName::DateTimeTuple
+            | TypeName::DateTuple
+            | TypeName::OffsetDateTime
+            | TypeName::ZonedDateTime => "date",
+            TypeName::Duration | TypeName::SignedDuration => "duration",
+            TypeName::MonthTuple => "month",
+            TypeName::QuarterTuple => "quarter",
+            TypeName::TimeTuple => "time",
+            TypeName::UtcOffset => "offset",
+            TypeName::WeekTuple => "week",
+            TypeName::Weekday => "weekday",
+            TypeName::YearTuple => "year",
+        }
+    }
+}
+
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// The error type returned by all fallible operations in this crate.
+///
+/// Values which failed validation are held in their `Debug` representation
+/// alongside the name of the type which rejected them.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub enum Error {
+    /// A year greater than 9999.
+    InvalidYear { type_name: TypeName, value: String },
+    /// A month outside of 1 to 12.
+    InvalidMonth { type_name: TypeName, value: String },
+    /// A quarter outside of 1 to 4.
+    InvalidQuarter { type_name: TypeName, value: String },
+    /// An ISO week which does not exist in its week-numbering year.
+    InvalidWeek {
+        type_name: TypeName,
+        value: String,
+        weeks_in_year: u8,
+    },
+    /// A day of the month which does not exist in its month.
+    InvalidDay { type_name: TypeName, value: String },
+    /// A day of the year which does not exist in its year.
+    InvalidDayOfYear { year: u16, day: u16 },
+    /// A weekday number outside of 1 to 7 (counting from Monday) or 0 to 6 (counting from Sunday).
+    InvalidWeekday { value: u8, from_sunday: bool },
+    /// A UTC offset of more than 18 hours, or with components of differing signs.
+    InvalidOffset { seconds: i32 },
+    /// A string which does not match any format accepted by the type being parsed.
+    InvalidFormat {
+        type_name: TypeName,
+        input: String,
+        expected: &'static str,
+    },
+    /// A string in the format expected by `from_str()` which holds an invalid value,
+    /// such as a month of 13, along with the error for that value.
+    InvalidParsedValue {
+        type_name: TypeName,
+        error: Box<Error>,
+    },
+    /// A formatting pattern containing an unrecognised directive, where `position` is
+    /// the byte offset of the directive's `%`.
+    InvalidDirective { pattern: String, position: usize },
+    /// A formatting directive for a value which the type being formatted does not hold,
+    /// such as `%d` on a `MonthTuple`.
+    UnsupportedDirective {
+        type_name: TypeName,
+        directive: char,
+    },
+    /// A string which does not match the pattern it was parsed with, or which contradicts
+    /// itself, such as by naming the wrong weekday for its date. `position` is the byte
+    /// offset of the offending part of the string.
+    ParseError {
+        input: String,
+        position: usize,
+        expected: String,
+    },
+    /// A parsing pattern which does not contain enough directives to produce the type
+    /// being parsed, such as a pattern without `%d` for a `DateTuple`.
+    IncompletePattern {
+        type_name: TypeName,
+        missing: &'static str,
+    },
+    /// An ISO 8601 duration containing years or months, which have no fixed length.
+    AmbiguousDuration { input: String },
+    /// A locale code which is neither built in nor registered.
+    UnknownLocale { code: String },
+    /// A time zone name with no TZif file in the zoneinfo directory.
+    UnknownTimeZone { name: String },
+    /// A TZif file which could not be read or is malformed, with the reason why.
+    InvalidTimeZone { name: String, reason: String },
+    /// A value which is valid in itself but falls outside of the range a type can hold.
+    OutOfRange {
+        type_name: TypeName,
+        value: String,
+        min: String,
+        max: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // DateTuple has always worded its year errors differently.
+            Error::InvalidYear {
+                type_name: TypeName::DateTuple,
+                value,
+            } => write!(
+                f,
+                "Invalid year in DateTuple {}: year must be <= 9999.",
+                value
+            ),
+            Error::InvalidYear { type_name, value } => write!(
+                f,
+                "Invalid year in {}: {}\nYear must be <= 9999.",
+                type_name, value
+            ),
+            Error::InvalidMonth { type_name, value } => write!(
+                f,
+                "Invalid month in {}: {}\nMonth must be between 1 and 12; Note that months are ONE-BASED since version 2.0.0.",
+                type_name, value
+            ),
+            Error::InvalidQuarter { type_name, value } => write!(
+                f,
+                "Invalid quarter in {}: {}\nQuarter must be between 1 and 4.",
+                type_name, value
+            ),
+            Error::InvalidWeek {
+                type_name,
+                value,
+                weeks_in_year,
+            } => write!(
+                f,
+                "Invalid week in {}: {}\nWeek must be between 1 and {} in that year.",
+                type_name, value, weeks_in_year
+            ),
+            Error::InvalidDay { type_name, value } => {
+                write!(f, "Invalid date in {}: {}", type_name, value)
+            }
+            Error::InvalidDayOfYear { year, day } => write!(
+                f,
+                "Invalid day of year in DateTuple: {:04}-{:03}\nDay must be between 1 and {} in {:04}.",
+                year,
+                day,
+                if date_utils::is_leap_year(i64::from(*year)) { 366 } else { 365 },
+                year
+            ),
+            Error::InvalidWeekday { value, from_sunday } => {
+                if *from_sunday {
+                    write!(f, "Invalid weekday number: {}\nWeekday numbers from Sunday must be between 0 and 6.", value)
+                } else {
+                    write!(f, "Invalid weekday number: {}\nWeekday numbers from Monday must be between 1 and 7.", value)
+                }
+            }
+            Error::InvalidOffset { seconds } => write!(
+                f,
+                "Invalid UTC offset: {} seconds\nOffsets must be at most 18 hours either way, with hours, minutes, and seconds of the same sign.",
+                seconds
+            ),
+            Error::InvalidFormat {
+                type_name,
+                input,
+                expected,
+            } => write!(
+                f,
+                "Invalid str formatting of {}: {}\nExpects a string formatted like {}{}",
+                type_name,
+                input,
+                expected,
+                if *type_name == TypeName::DateTuple {
+                    "."
+                } else {
+                    ""
+                }
+            ),
+            Error::InvalidParsedValue { type_name, error } => write!(
+                f,
+                "Invalid {} passed to from_str: {}",
+                type_name.value_noun(),
+                error
+            ),
+            Error::InvalidDirective { pattern, position } => write!(
+                f,
+                "Invalid directive in pattern {} at position {}",
+                pattern, position
+            ),
+            Error::UnsupportedDirective {
+                type_name,
+                directive,
+            } => write!(
+                f,
+                "Directive %{} cannot be used to format {}",
+                directive, type_name
+            ),
+            Error::ParseError {
+                input,
+                position,
+                expected,
+            } => write!(
+                f,
+                "Unable to parse {} at position {}\nExpected {}.",
+                input, position, expected
+            ),
+            Error::IncompletePattern { type_name, missing } => write!(
+                f,
+                "Pattern does not contain a {} for {}",
+                missing, type_name
+            ),
+            Error::AmbiguousDuration { input } => write!(
+                f,
+                "Ambiguous ISO 8601 duration: {}\nYears and months have no fixed length; use weeks, days, or hours instead.",
+                input
+            ),
+            Error::UnknownLocale { code } => write!(f, "Unknown locale: {}", code),
+            Error::UnknownTimeZone { name } => write!(f, "Unknown time zone: {}", name),
+            Error::InvalidTimeZone { name, reason } => {
+                write!(f, "Invalid time zone data for {}: {}", name, reason)
+            }
+            Error::OutOfRange {
+                type_name,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "Out of range for {}: {}\nValues must be between {} and {}.",
+                type_name, value, min, max
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::InvalidParsedValue { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
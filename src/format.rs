@@ -0,0 +1,456 @@
+//! strftime-like formatting of the tuple types.
+//!
+//! A pattern is made up of literal text and directives, each introduced by `%`:
+//!
+//! | Directive | Meaning | Example |
+//! |-----------|---------|---------|
+//! | `%Y` | Year, four digits | 2018 |
+//! | `%y` | Year within the century, two digits | 18 |
+//! | `%m` | Month, two digits | 10 |
+//! | `%b` | Abbreviated month name | Oct |
+//! | `%B` | Full month name | October |
+//! | `%q` | Quarter of the year | 4 |
+//! | `%d` | Day of the month, two digits | 02 |
+//! | `%j` | Day of the year, three digits | 275 |
+//! | `%a` | Abbreviated weekday name | Tue |
+//! | `%A` | Full weekday name | Tuesday |
+//! | `%u` | Weekday number from Monday (1 to 7) | 2 |
+//! | `%w` | Weekday number from Sunday (0 to 6) | 2 |
+//! | `%V` | ISO 8601 week number, two digits | 40 |
+//! | `%G` | ISO 8601 week-numbering year, four digits | 2018 |
+//! | `%H` | Hour, two digits (total hours for a `Duration`) | 20 |
+//! | `%I` | Hour on a 12-hour clock, two digits | 08 |
+//! | `%p` | AM or PM | PM |
+//! | `%M` | Minute, two digits | 30 |
+//! | `%S` | Second, two digits | 05 |
+//! | `%F` | Shorthand for `%Y-%m-%d` | 2018-10-02 |
+//! | `%T` | Shorthand for `%H:%M:%S` | 20:30:05 |
+//! | `%%` | A literal `%` | % |
+//!
+//! Numeric directives are padded with zeroes; adding `-` after the `%`, such as `%-d`,
+//! removes the padding.
+//!
+//! Names and AM/PM markers are in English unless the pattern is given a different
+//! `Locale` with `Pattern::with_locale()`.
+//!
+//! Patterns can be compiled once into a `Pattern` and reused, which avoids re-parsing
+//! the pattern each time a value is formatted.
+//!
+//! Not every directive is available on every type: a `MonthTuple` has no day, and a
+//! `TimeTuple` has no date. Using a directive which a type cannot provide results in
+//! `Error::UnsupportedDirective`.
+
+use date_time_tuple::DateTimeTuple;
+use date_tuple::DateTuple;
+use error::{Error, TypeName};
+use locale::Locale;
+use month_tuple::MonthTuple;
+use quarter_tuple::QuarterTuple;
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::str::FromStr;
+use time_tuple::{Duration, TimeTuple};
+use week_tuple::WeekTuple;
+
+/// A single `%` directive within a pattern.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub(crate) enum Directive {
+    Year,
+    YearOfCentury,
+    Month,
+    MonthShortName,
+    MonthName,
+    Quarter,
+    Day,
+    DayOfYear,
+    WeekdayShortName,
+    WeekdayName,
+    WeekdayFromMonday,
+    WeekdayFromSunday,
+    IsoWeek,
+    IsoWeekYear,
+    Hour,
+    Hour12,
+    AmPm,
+    Minute,
+    Second,
+}
+
+impl Directive {
+    fn from_char(c: char) -> Option<Directive> {
+        match c {
+            'Y' => Some(Directive::Year),
+            'y' => Some(Directive::YearOfCentury),
+            'm' => Some(Directive::Month),
+            'b' => Some(Directive::MonthShortName),
+            'B' => Some(Directive::MonthName),
+            'q' => Some(Directive::Quarter),
+            'd' => Some(Directive::Day),
+            'j' => Some(Directive::DayOfYear),
+            'a' => Some(Directive::WeekdayShortName),
+            'A' => Some(Directive::WeekdayName),
+            'u' => Some(Directive::WeekdayFromMonday),
+            'w' => Some(Directive::WeekdayFromSunday),
+            'V' => Some(Directive::IsoWeek),
+            'G' => Some(Directive::IsoWeekYear),
+            'H' => Some(Directive::Hour),
+            'I' => Some(Directive::Hour12),
+            'p' => Some(Directive::AmPm),
+            'M' => Some(Directive::Minute),
+            'S' => Some(Directive::Second),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_char(self) -> char {
+        match self {
+            Directive::Year => 'Y',
+            Directive::YearOfCentury => 'y',
+            Directive::Month => 'm',
+            Directive::MonthShortName => 'b',
+            Directive::MonthName => 'B',
+            Directive::Quarter => 'q',
+            Directive::Day => 'd',
+            Directive::DayOfYear => 'j',
+            Directive::WeekdayShortName => 'a',
+            Directive::WeekdayName => 'A',
+            Directive::WeekdayFromMonday => 'u',
+            Directive::WeekdayFromSunday => 'w',
+            Directive::IsoWeek => 'V',
+            Directive::IsoWeekYear => 'G',
+            Directive::Hour => 'H',
+            Directive::Hour12 => 'I',
+            Directive::AmPm => 'p',
+            Directive::Minute => 'M',
+            Directive::Second => 'S',
+        }
+    }
+
+    /// The number of digits a numeric directive is padded to, or `None` for text.
+    pub(crate) fn width(self) -> Option<usize> {
+        match self {
+            Directive::Year | Directive::IsoWeekYear => Some(4),
+            Directive::DayOfYear => Some(3),
+            Directive::YearOfCentury
+            | Directive::Month
+            | Directive::Day
+            | Directive::IsoWeek
+            | Directive::Hour
+            | Directive::Hour12
+            | Directive::Minute
+            | Directive::Second => Some(2),
+            Directive::Quarter | Directive::WeekdayFromMonday | Directive::WeekdayFromSunday => {
+                Some(1)
+            }
+            Directive::MonthShortName
+            | Directive::MonthName
+            | Directive::WeekdayShortName
+            | Directive::WeekdayName
+            | Directive::AmPm => None,
+        }
+    }
+}
+
+/// One component of a compiled `Pattern`.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub(crate) enum Item {
+    Literal(String),
+    Directive { directive: Directive, padded: bool },
+}
+
+/// A compiled formatting pattern.
+///
+/// Compiling a pattern once with `Pattern::new()` and passing it to the `format_with()`
+/// methods avoids parsing the pattern again every time a value is formatted.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct Pattern {
+    items: Vec<Item>,
+    locale: Locale,
+}
+
+impl Pattern {
+    /// Compiles a pattern, failing with `Error::InvalidDirective` if it contains
+    /// an unrecognised directive.
+    pub fn new(pattern: &str) -> Result<Pattern, Error> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices();
+        while let Some((position, c)) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let invalid = || Error::InvalidDirective {
+                pattern: pattern.to_string(),
+                position,
+            };
+            let mut spec = chars.next().ok_or_else(invalid)?.1;
+            let padded = spec != '-';
+            if !padded {
+                spec = chars.next().ok_or_else(invalid)?.1;
+            }
+            let (directives, separator) = match spec {
+                '%' if padded => {
+                    literal.push('%');
+                    continue;
+                }
+                'F' => (vec![Directive::Year, Directive::Month, Directive::Day], "-"),
+                'T' => (
+                    vec![Directive::Hour, Directive::Minute, Directive::Second],
+                    ":",
+                ),
+                _ => match Directive::from_char(spec) {
+                    Some(directive) if padded || directive.width().is_some() => {
+                        (vec![directive], "")
+                    }
+                    _ => return Err(invalid()),
+                },
+            };
+            for (i, directive) in directives.into_iter().enumerate() {
+                if i > 0 {
+                    literal.push_str(separator);
+                }
+                if !literal.is_empty() {
+                    items.push(Item::Literal(literal.split_off(0)));
+                }
+                items.push(Item::Directive { directive, padded });
+            }
+        }
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+        Ok(Pattern {
+            items,
+            locale: Locale::EN,
+        })
+    }
+
+    /// Sets the locale used for month and weekday names and AM/PM markers,
+    /// both when formatting and when parsing.
+    ///
+    /// ```
+    /// # use date_time::date_tuple::DateTuple;
+    /// # use date_time::format::Pattern;
+    /// # use date_time::locale::Locale;
+    /// let pattern = Pattern::new("%A %-d %B %Y").unwrap().with_locale(Locale::FR);
+    /// let date = DateTuple::new(2018, 10, 2).unwrap();
+    /// assert_eq!("mardi 2 octobre 2018", date.format_with(&pattern).unwrap());
+    /// assert_eq!(Ok(date), DateTuple::parse_with("mardi 2 octobre 2018", &pattern));
+    /// ```
+    pub fn with_locale(mut self, locale: Locale) -> Pattern {
+        self.locale = locale;
+        self
+    }
+
+    pub fn get_locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub(crate) fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    pub(crate) fn format_value(&self, value: Value) -> Result<String, Error> {
+        let mut out = String::new();
+        for item in &self.items {
+            match item {
+                Item::Literal(s) => out.push_str(s),
+                Item::Directive { directive, padded } => {
+                    write_directive(&mut out, *directive, *padded, value, &self.locale)?
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Pattern, Self::Err> {
+        Pattern::new(s)
+    }
+}
+
+/// A value of any of the formattable types.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Value {
+    Date(DateTuple),
+    Month(MonthTuple),
+    Time(TimeTuple),
+    DateTime(DateTimeTuple),
+    Duration(Duration),
+}
+
+impl Value {
+    fn type_name(self) -> TypeName {
+        match self {
+            Value::Date(_) => TypeName::DateTuple,
+            Value::Month(_) => TypeName::MonthTuple,
+            Value::Time(_) => TypeName::TimeTuple,
+            Value::DateTime(_) => TypeName::DateTimeTuple,
+            Value::Duration(_) => TypeName::Duration,
+        }
+    }
+
+    fn date(self) -> Option<DateTuple> {
+        match self {
+            Value::Date(d) => Some(d),
+            Value::DateTime(dt) => Some(dt.get_date()),
+            _ => None,
+        }
+    }
+
+    fn month(self) -> Option<MonthTuple> {
+        match self {
+            Value::Month(m) => Some(m),
+            _ => self.date().map(MonthTuple::from),
+        }
+    }
+
+    fn time(self) -> Option<TimeTuple> {
+        match self {
+            Value::Time(t) => Some(t),
+            Value::DateTime(dt) => Some(dt.get_time()),
+            _ => None,
+        }
+    }
+
+    /// Gets the hours, minutes, and seconds of either a time or a duration.
+    fn hms(self) -> Option<(u32, u8, u8)> {
+        match self {
+            Value::Duration(d) => Some((d.get_hours(), d.get_minutes(), d.get_seconds())),
+            _ => self
+                .time()
+                .map(|t| (u32::from(t.get_hours()), t.get_minutes(), t.get_seconds())),
+        }
+    }
+}
+
+enum Output<'a> {
+    Number(u32),
+    Text(&'a str),
+}
+
+fn write_directive(
+    out: &mut String,
+    directive: Directive,
+    padded: bool,
+    value: Value,
+    locale: &Locale,
+) -> Result<(), Error> {
+    let unsupported = || Error::UnsupportedDirective {
+        type_name: value.type_name(),
+        directive: directive.to_char(),
+    };
+    let week = |date: DateTuple| WeekTuple::try_from(date).map_err(|_| unsupported());
+    let output = match directive {
+        Directive::Year => {
+            Output::Number(u32::from(value.month().ok_or_else(unsupported)?.get_year()))
+        }
+        Directive::YearOfCentury => Output::Number(u32::from(
+            value.month().ok_or_else(unsupported)?.get_year() % 100,
+        )),
+        Directive::Month => Output::Number(u32::from(
+            value.month().ok_or_else(unsupported)?.get_month(),
+        )),
+        Directive::MonthShortName => Output::Text(
+            locale.short_month_name(value.month().ok_or_else(unsupported)?.get_month()),
+        ),
+        Directive::MonthName => {
+            Output::Text(locale.month_name(value.month().ok_or_else(unsupported)?.get_month()))
+        }
+        Directive::Quarter => Output::Number(u32::from(
+            QuarterTuple::from(value.month().ok_or_else(unsupported)?).get_quarter(),
+        )),
+        Directive::Day => {
+            Output::Number(u32::from(value.date().ok_or_else(unsupported)?.get_date()))
+        }
+        Directive::DayOfYear => Output::Number(u32::from(
+            value.date().ok_or_else(unsupported)?.day_of_year(),
+        )),
+        Directive::WeekdayShortName => {
+            Output::Text(locale.short_weekday_name(value.date().ok_or_else(unsupported)?.weekday()))
+        }
+        Directive::WeekdayName => {
+            Output::Text(locale.weekday_name(value.date().ok_or_else(unsupported)?.weekday()))
+        }
+        Directive::WeekdayFromMonday => Output::Number(u32::from(
+            value
+                .date()
+                .ok_or_else(unsupported)?
+                .weekday()
+                .number_from_monday(),
+        )),
+        Directive::WeekdayFromSunday => Output::Number(u32::from(
+            value
+                .date()
+                .ok_or_else(unsupported)?
+                .weekday()
+                .number_from_sunday(),
+        )),
+        Directive::IsoWeek => Output::Number(u32::from(
+            week(value.date().ok_or_else(unsupported)?)?.get_week(),
+        )),
+        Directive::IsoWeekYear => Output::Number(u32::from(
+            week(value.date().ok_or_else(unsupported)?)?.get_year(),
+        )),
+        Directive::Hour => Output::Number(value.hms().ok_or_else(unsupported)?.0),
+        Directive::Hour12 => {
+            let hours = value.time().ok_or_else(unsupported)?.get_hours();
+            Output::Number(match hours % 12 {
+                0 => 12,
+                h => u32::from(h),
+            })
+        }
+        Directive::AmPm => {
+            Output::Text(locale.am_pm(value.time().ok_or_else(unsupported)?.get_hours()))
+        }
+        Directive::Minute => Output::Number(u32::from(value.hms().ok_or_else(unsupported)?.1)),
+        Directive::Second => Output::Number(u32::from(value.hms().ok_or_else(unsupported)?.2)),
+    };
+    match output {
+        Output::Text(s) => out.push_str(s),
+        Output::Number(n) if padded => {
+            write!(out, "{:0width$}", n, width = directive.width().unwrap()).unwrap()
+        }
+        Output::Number(n) => write!(out, "{}", n).unwrap(),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{Directive, Item, Pattern};
+
+    #[test]
+    fn test_compile_expands_shorthands() {
+        let pattern = Pattern::new("%F at %-H%%").unwrap();
+        assert_eq!(
+            vec![
+                Item::Directive {
+                    directive: Directive::Year,
+                    padded: true
+                },
+                Item::Literal("-".to_string()),
+                Item::Directive {
+                    directive: Directive::Month,
+                    padded: true
+                },
+                Item::Literal("-".to_string()),
+                Item::Directive {
+                    directive: Directive::Day,
+                    padded: true
+                },
+                Item::Literal(" at ".to_string()),
+                Item::Directive {
+                    directive: Directive::Hour,
+                    padded: false
+                },
+                Item::Literal("%".to_string()),
+            ],
+            pattern.items
+        );
+    }
+}
@@ -2,8 +2,26 @@ extern crate regex;
 #[macro_use]
 extern crate lazy_static;
 
+pub mod clock;
 pub mod date_time_tuple;
 pub mod date_tuple;
 mod date_utils;
+pub mod error;
+pub mod format;
+pub mod locale;
 pub mod month_tuple;
+mod natural;
+pub mod offset_date_time;
+mod parse;
+pub mod posix_tz;
+pub mod quarter_tuple;
+pub mod relative;
 pub mod time_tuple;
+pub mod tz;
+pub mod utc_offset;
+pub mod week_tuple;
+pub mod weekday;
+pub mod year_tuple;
+pub mod zoned_date_time;
+
+pub use error::Error;
@@ -0,0 +1,321 @@
+//! Month and weekday names, AM/PM markers, and date order for different languages.
+//!
+//! Built-in locales are available as constants such as `Locale::FR`, or by code with
+//! `Locale::find()`. Other locales can be added with `Locale::register()`, after which
+//! they can be found by code in the same way.
+//!
+//! A `Locale` is used by the `to_readable_string_in()` methods, and by patterns
+//! given one with `Pattern::with_locale()` for both formatting and parsing.
+
+use error::Error;
+use month_tuple::{MONTH_NAMES, MONTH_STRINGS};
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::sync::RwLock;
+use weekday::{Weekday, WEEKDAY_STRINGS};
+
+lazy_static! {
+    static ref REGISTERED: RwLock<Vec<Locale>> = RwLock::new(Vec::new());
+}
+
+/// Builds a table of names for a built-in locale, either from string literals or
+/// from the given indices of an existing table.
+macro_rules! names {
+    ($table:ident; $($i:expr),+) => {
+        [$(Cow::Borrowed($table[$i])),+]
+    };
+    ($($name:expr),+ $(,)*) => {
+        [$(Cow::Borrowed($name)),+]
+    };
+}
+
+/// The order in which the day, month, and year of a date are usually written.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+/// The names and conventions used to write dates and times in a language.
+///
+/// The built-in locales borrow their names, while those built with `Locale::new()`
+/// may own them instead.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct Locale {
+    code: Cow<'static, str>,
+    months: [Cow<'static, str>; 12],
+    short_months: [Cow<'static, str>; 12],
+    weekdays: [Cow<'static, str>; 7],
+    short_weekdays: [Cow<'static, str>; 7],
+    am_pm: [Cow<'static, str>; 2],
+    date_order: DateOrder,
+}
+
+impl Locale {
+    /// English, with the day before the month: 2 Oct 2018.
+    pub const EN: Locale = Locale {
+        code: Cow::Borrowed("en"),
+        months: names!(MONTH_NAMES; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11),
+        short_months: names!(MONTH_STRINGS; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11),
+        weekdays: names!(WEEKDAY_STRINGS; 0, 1, 2, 3, 4, 5, 6),
+        short_weekdays: names!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        am_pm: names!["AM", "PM"],
+        date_order: DateOrder::DayMonthYear,
+    };
+
+    /// American English, with the month before the day: Oct 2, 2018.
+    pub const EN_US: Locale = Locale {
+        code: Cow::Borrowed("en-US"),
+        date_order: DateOrder::MonthDayYear,
+        ..Locale::EN
+    };
+
+    /// French.
+    pub const FR: Locale = Locale {
+        code: Cow::Borrowed("fr"),
+        months: names![
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        short_months: names![
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: names!["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",],
+        short_weekdays: names!["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        am_pm: names!["AM", "PM"],
+        date_order: DateOrder::DayMonthYear,
+    };
+
+    /// German.
+    pub const DE: Locale = Locale {
+        code: Cow::Borrowed("de"),
+        months: names![
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        short_months: names![
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        weekdays: names![
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        short_weekdays: names!["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        am_pm: names!["AM", "PM"],
+        date_order: DateOrder::DayMonthYear,
+    };
+
+    /// Spanish.
+    pub const ES: Locale = Locale {
+        code: Cow::Borrowed("es"),
+        months: names![
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        short_months: names![
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: names![
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        short_weekdays: names!["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        am_pm: names!["a. m.", "p. m."],
+        date_order: DateOrder::DayMonthYear,
+    };
+
+    /// Japanese.
+    pub const JA: Locale = Locale {
+        code: Cow::Borrowed("ja"),
+        months: names![
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        short_months: names![
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: names![
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        short_weekdays: names!["月", "火", "水", "木", "金", "土", "日"],
+        am_pm: names!["午前", "午後"],
+        date_order: DateOrder::YearMonthDay,
+    };
+
+    const BUILT_IN: [Locale; 6] = [
+        Locale::EN,
+        Locale::EN_US,
+        Locale::FR,
+        Locale::DE,
+        Locale::ES,
+        Locale::JA,
+    ];
+
+    /// Produces a locale from its tables, which may be string literals or
+    /// `String`s built at runtime.
+    ///
+    /// Months are listed from January and weekdays from Monday.
+    pub fn new<S: Into<Cow<'static, str>>>(
+        code: S,
+        months: [S; 12],
+        short_months: [S; 12],
+        weekdays: [S; 7],
+        short_weekdays: [S; 7],
+        am_pm: [S; 2],
+        date_order: DateOrder,
+    ) -> Locale {
+        Locale {
+            code: code.into(),
+            months: months.map(Into::into),
+            short_months: short_months.map(Into::into),
+            weekdays: weekdays.map(Into::into),
+            short_weekdays: short_weekdays.map(Into::into),
+            am_pm: am_pm.map(Into::into),
+            date_order,
+        }
+    }
+
+    /// Finds a registered or built-in locale by its code, ignoring case.
+    ///
+    /// A code with a region which isn't known, such as `fr-CA`, falls back to
+    /// its language, `fr`. Registered locales take precedence over built-in ones.
+    pub fn find(code: &str) -> Option<Locale> {
+        let lookup = |code: &str| {
+            let registered = REGISTERED.read().unwrap();
+            registered
+                .iter()
+                .chain(Locale::BUILT_IN.iter())
+                .find(|locale| locale.code.eq_ignore_ascii_case(code))
+                .cloned()
+        };
+        lookup(code).or_else(|| code.find(['-', '_']).and_then(|i| lookup(&code[..i])))
+    }
+
+    /// Makes this locale available to `Locale::find()` by its code,
+    /// replacing any locale previously registered with the same code.
+    pub fn register(self) {
+        let mut registered = REGISTERED.write().unwrap();
+        registered.retain(|locale| !locale.code.eq_ignore_ascii_case(&self.code));
+        registered.push(self);
+    }
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn get_date_order(&self) -> DateOrder {
+        self.date_order
+    }
+
+    /// Gets the full name of a month, numbered from 1 for January.
+    ///
+    /// Panics if the month is not between 1 and 12.
+    pub fn month_name(&self, month: u8) -> &str {
+        &self.months[month as usize - 1]
+    }
+
+    /// Gets the abbreviated name of a month, numbered from 1 for January.
+    ///
+    /// Panics if the month is not between 1 and 12.
+    pub fn short_month_name(&self, month: u8) -> &str {
+        &self.short_months[month as usize - 1]
+    }
+
+    pub fn weekday_name(&self, weekday: Weekday) -> &str {
+        &self.weekdays[weekday as usize]
+    }
+
+    pub fn short_weekday_name(&self, weekday: Weekday) -> &str {
+        &self.short_weekdays[weekday as usize]
+    }
+
+    /// Gets the marker for a time with the given hour: AM before noon, or PM after.
+    pub fn am_pm(&self, hour: u8) -> &str {
+        &self.am_pm[if hour < 12 { 0 } else { 1 }]
+    }
+
+    pub(crate) fn month_names(&self) -> &[Cow<'static, str>; 12] {
+        &self.months
+    }
+
+    pub(crate) fn short_month_names(&self) -> &[Cow<'static, str>; 12] {
+        &self.short_months
+    }
+
+    pub(crate) fn weekday_names(&self) -> &[Cow<'static, str>; 7] {
+        &self.weekdays
+    }
+
+    pub(crate) fn short_weekday_names(&self) -> &[Cow<'static, str>; 7] {
+        &self.short_weekdays
+    }
+
+    pub(crate) fn am_pm_markers(&self) -> &[Cow<'static, str>; 2] {
+        &self.am_pm
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::EN
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Error;
+
+    /// Finds a locale by its code, as with `Locale::find()`.
+    fn from_str(s: &str) -> Result<Locale, Self::Err> {
+        Locale::find(s).ok_or_else(|| Error::UnknownLocale {
+            code: s.to_string(),
+        })
+    }
+}
@@ -1,15 +1,38 @@
+use clock::Clock;
 use date_tuple::DateTuple;
 use date_utils;
+use error::{Error, TypeName};
+use format::{Pattern, Value};
+use locale::{DateOrder, Locale};
+use parse::{self, Kind};
 use regex::Regex;
 use std::cmp::Ordering;
 use std::convert::From;
 use std::fmt;
 use std::str::FromStr;
 
-const MONTH_STRINGS: [&str; 12] = [
+pub(crate) const MONTH_STRINGS: [&str; 12] = [
     "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
 ];
 
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The month index of Dec 9999 as produced by `MonthTuple::to_month_index()`.
+const MAX_MONTH_INDEX: u32 = 9999 * 12 + 11;
+
 pub type Month = MonthTuple;
 
 /// A container for a month of a specific year.
@@ -33,21 +56,21 @@ impl MonthTuple {
     /// Only accepts a valid month value (`1 <= m <= 12`).
     ///
     /// Only accepts a valid year value (`0 <= y <= 9999`).
-    pub fn new(y: u16, m: u8) -> Result<MonthTuple, String> {
+    pub fn new(y: u16, m: u8) -> Result<MonthTuple, Error> {
         if (1..=12).contains(&m) {
             if y <= 9999 {
                 Ok(MonthTuple { y, m })
             } else {
-                Err(format!(
-                    "Invalid year in MonthTuple: {:?}\nYear must be <= 9999.",
-                    MonthTuple { y, m }
-                ))
+                Err(Error::InvalidYear {
+                    type_name: TypeName::MonthTuple,
+                    value: format!("{:?}", MonthTuple { y, m }),
+                })
             }
         } else {
-            Err(format!(
-                "Invalid month in MonthTuple: {:?}\nMonth must be between 1 and 12; Note that months are ONE-BASED since version 2.0.0.",
-                MonthTuple { y, m }
-            ))
+            Err(Error::InvalidMonth {
+                type_name: TypeName::MonthTuple,
+                value: format!("{:?}", MonthTuple { y, m }),
+            })
         }
     }
 
@@ -56,6 +79,11 @@ impl MonthTuple {
         date_utils::now_as_monthtuple()
     }
 
+    /// Returns a `MonthTuple` of the current month in UTC according to a clock.
+    pub fn this_month_with_clock<C: Clock + ?Sized>(clock: &C) -> MonthTuple {
+        MonthTuple::from(clock.now().get_date())
+    }
+
     pub fn get_year(self) -> u16 {
         self.y
     }
@@ -106,35 +134,121 @@ impl MonthTuple {
     }
 
     /// Adds a number of months to a MonthTuple.
+    ///
+    /// Will not go past Dec 9999.
     pub fn add_months(&mut self, months: u32) {
-        for _ in 0..months {
-            *self = self.next_month();
-        }
+        let new_index = self
+            .to_month_index()
+            .saturating_add(months)
+            .min(MAX_MONTH_INDEX);
+        *self = MonthTuple::from_month_index(new_index);
     }
 
     /// Subtracts a number of months from a MonthTuple.
+    ///
+    /// Will not go past Jan 0000.
     pub fn subtract_months(&mut self, months: u32) {
-        for _ in 0..months {
-            *self = self.previous_month();
+        let new_index = self.to_month_index().saturating_sub(months);
+        *self = MonthTuple::from_month_index(new_index);
+    }
+
+    /// Gets the number of months between Jan 0000 and this month,
+    /// with Jan 0000 being zero.
+    fn to_month_index(self) -> u32 {
+        u32::from(self.y) * 12 + u32::from(self.m) - 1
+    }
+
+    /// The inverse of `to_month_index()`. The index must not exceed `MAX_MONTH_INDEX`.
+    fn from_month_index(index: u32) -> MonthTuple {
+        MonthTuple {
+            y: (index / 12) as u16,
+            m: (index % 12) as u8 + 1,
         }
     }
 
     /// Adds a number of years to a MonthTuple.
     pub fn add_years(&mut self, years: u16) {
-        let mut new_years = self.y + years;
-        if new_years > 9999 {
-            new_years = 9999;
-        }
-        self.y = new_years;
+        self.y = self.y.saturating_add(years).min(9999);
     }
 
     /// Subtracts a number of years from a MonthTuple.
     pub fn subtract_years(&mut self, years: u16) {
-        let mut new_years = i32::from(self.y) - i32::from(years);
-        if new_years < 0 {
-            new_years = 0;
+        self.y = self.y.saturating_sub(years);
+    }
+
+    /// Adds a number of months to a MonthTuple, returning `None` if the
+    /// result would fall outside of the range the MonthTuple can hold.
+    pub fn checked_add_months(self, months: u32) -> Option<MonthTuple> {
+        self.to_month_index()
+            .checked_add(months)
+            .filter(|i| *i <= MAX_MONTH_INDEX)
+            .map(MonthTuple::from_month_index)
+    }
+
+    /// Subtracts a number of months from a MonthTuple, returning `None` if the
+    /// result would fall outside of the range the MonthTuple can hold.
+    pub fn checked_sub_months(self, months: u32) -> Option<MonthTuple> {
+        self.to_month_index()
+            .checked_sub(months)
+            .map(MonthTuple::from_month_index)
+    }
+
+    /// Adds a number of years to a MonthTuple, returning `None` if the
+    /// result would fall outside of the range the MonthTuple can hold.
+    pub fn checked_add_years(self, years: u16) -> Option<MonthTuple> {
+        let new_years = u32::from(self.y) + u32::from(years);
+        if new_years > 9999 {
+            None
+        } else {
+            Some(MonthTuple {
+                y: new_years as u16,
+                m: self.m,
+            })
         }
-        self.y = new_years as u16;
+    }
+
+    /// Subtracts a number of years from a MonthTuple, returning `None` if the
+    /// result would fall outside of the range the MonthTuple can hold.
+    pub fn checked_sub_years(self, years: u16) -> Option<MonthTuple> {
+        self.y
+            .checked_sub(years)
+            .map(|y| MonthTuple { y, m: self.m })
+    }
+
+    /// Adds a number of months to a MonthTuple, stopping at Dec 9999.
+    ///
+    /// The same as `add_months()`, but produces a new MonthTuple.
+    pub fn saturating_add_months(self, months: u32) -> MonthTuple {
+        let mut result = self;
+        result.add_months(months);
+        result
+    }
+
+    /// Subtracts a number of months from a MonthTuple, stopping at Jan 0000.
+    ///
+    /// The same as `subtract_months()`, but produces a new MonthTuple.
+    pub fn saturating_sub_months(self, months: u32) -> MonthTuple {
+        let mut result = self;
+        result.subtract_months(months);
+        result
+    }
+
+    /// Adds a number of years to a MonthTuple, stopping at Dec 9999.
+    ///
+    /// The same as `add_years()`, but produces a new MonthTuple.
+    pub fn saturating_add_years(self, years: u16) -> MonthTuple {
+        let mut result = self;
+        result.add_years(years);
+        result
+    }
+
+    /// Subtracts a number of years from a MonthTuple, stopping at Jan 0000.
+    ///
+    /// The same as `subtract_years()`, but produces a new MonthTuple.
+    pub fn saturating_sub_years(self, years: u16) -> MonthTuple {
+        let mut result = self;
+        result.subtract_years(years);
+        result
     }
 
     /// Returns the month formatted to be human-readable.
@@ -143,11 +257,48 @@ impl MonthTuple {
     /// * Jan 2018
     /// * Dec 1994
     pub fn to_readable_string(self) -> String {
-        match MONTH_STRINGS.iter().nth(self.m as usize - 1) {
-            Some(s) => return format!("{} {:04}", s, self.y),
-            None => panic!("Invalid MonthTuple: {:?}", self),
+        self.to_readable_string_in(&Locale::EN)
+    }
+
+    /// Returns the month formatted to be human-readable in the given locale,
+    /// with the year first if that is the locale's date order.
+    ///
+    /// ## Examples
+    /// * oct. 2018 (`Locale::FR`)
+    /// * 2018 10月 (`Locale::JA`)
+    pub fn to_readable_string_in(self, locale: &Locale) -> String {
+        let name = locale.short_month_name(self.m);
+        match locale.get_date_order() {
+            DateOrder::YearMonthDay => format!("{:04} {}", self.y, name),
+            _ => format!("{} {:04}", name, self.y),
         }
     }
+
+    /// Formats the month using a strftime-like pattern; `"%B %Y"` produces `October 2018`.
+    ///
+    /// See the `format` module for the available directives.
+    pub fn format(self, pattern: &str) -> Result<String, Error> {
+        self.format_with(&Pattern::new(pattern)?)
+    }
+
+    /// Formats the month using a pattern compiled in advance with `Pattern::new()`.
+    pub fn format_with(self, pattern: &Pattern) -> Result<String, Error> {
+        pattern.format_value(Value::Month(self))
+    }
+
+    /// Parses a month from a string using a strftime-like pattern, such as `"%B %Y"`
+    /// for `October 2018`.
+    ///
+    /// See the `format` module for the available directives. If the string doesn't match
+    /// the pattern, `Error::ParseError` gives the position at which it stopped matching.
+    pub fn parse_from_str(input: &str, pattern: &str) -> Result<MonthTuple, Error> {
+        MonthTuple::parse_with(input, &Pattern::new(pattern)?)
+    }
+
+    /// Parses a month using a pattern compiled in advance with `Pattern::new()`.
+    pub fn parse_with(input: &str, pattern: &Pattern) -> Result<MonthTuple, Error> {
+        parse::parse(input, pattern, Kind::Month)?.to_month()
+    }
 }
 
 impl fmt::Display for MonthTuple {
@@ -157,7 +308,7 @@ impl fmt::Display for MonthTuple {
 }
 
 impl FromStr for MonthTuple {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<MonthTuple, Self::Err> {
         lazy_static! {
@@ -165,36 +316,31 @@ impl FromStr for MonthTuple {
             static ref LEGACY_FORMAT: Regex = Regex::new(r"^\d{6}$").unwrap();
         }
 
-        if VALID_FORMAT.is_match(s) {
-            match MonthTuple::new(
+        let month = if VALID_FORMAT.is_match(s) {
+            MonthTuple::new(
                 u16::from_str(&s[0..4]).unwrap(),
                 u8::from_str(&s[5..7]).unwrap(),
-            ) {
-                Ok(m) => Ok(m),
-                Err(e) => Err(format!("Invalid month passed to from_str: {}", e)),
-            }
+            )
         } else if LEGACY_FORMAT.is_match(s) {
             let (s1, s2) = s.split_at(4);
-            match MonthTuple::new(u16::from_str(s1).unwrap(), u8::from_str(s2).unwrap()) {
-                Ok(m) => Ok(m),
-                Err(e) => Err(format!("Invalid month passed to from_str: {}", e)),
-            }
+            MonthTuple::new(u16::from_str(s1).unwrap(), u8::from_str(s2).unwrap())
         } else {
-            Err(format!(
-                "Invalid str formatting of MonthTuple: {}\nExpects a string formatted like 2018-11",
-                s
-            ))
-        }
+            return Err(Error::InvalidFormat {
+                type_name: TypeName::MonthTuple,
+                input: s.to_string(),
+                expected: "2018-11",
+            });
+        };
+        month.map_err(|e| Error::InvalidParsedValue {
+            type_name: TypeName::MonthTuple,
+            error: Box::new(e),
+        })
     }
 }
 
 impl PartialOrd for MonthTuple {
     fn partial_cmp(&self, other: &MonthTuple) -> Option<Ordering> {
-        if self.y == other.y {
-            self.m.partial_cmp(&other.m)
-        } else {
-            self.y.partial_cmp(&other.y)
-        }
+        Some(self.cmp(other))
     }
 }
 
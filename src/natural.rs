@@ -0,0 +1,544 @@
+//! Resolving natural-language expressions such as "next Friday" or "tomorrow at 5pm".
+//!
+//! Expressions are resolved relative to a reference date and time supplied by the
+//! caller rather than the system clock, so the same input always gives the same result.
+//! Words are matched ignoring case, and commas are treated as spaces.
+
+use date_time_tuple::DateTimeTuple;
+use date_tuple::DateTuple;
+use date_utils;
+use error::{Error, TypeName};
+use month_tuple::{MONTH_NAMES, MONTH_STRINGS};
+use regex::Regex;
+use relative::Unit;
+use std::convert::TryFrom;
+use std::iter::once;
+use std::str::FromStr;
+use time_tuple::TimeTuple;
+use weekday::{Weekday, WEEKDAY_STRINGS};
+
+lazy_static! {
+    static ref TIME: Regex = Regex::new(r"^(\d{1,2})(?::(\d{2}))?(am|pm|a\.m\.|p\.m\.)?$").unwrap();
+}
+
+const EXPECTED_DATE: &str = "a date such as \"tomorrow\", \"next Friday\", or \"in 2 weeks\"";
+const EXPECTED_DATE_TIME: &str =
+    "a date or time such as \"tomorrow at 5pm\", \"next Friday\", or \"in 2 hours\"";
+const EXPECTED_TIME: &str = "a time such as 5pm or 17:30";
+
+/// Resolves `input` relative to `reference`.
+///
+/// Times of day and offsets in hours, minutes, or seconds are only accepted if
+/// `with_time` is set; otherwise the time of the result is that of `reference`.
+pub(crate) fn parse(
+    input: &str,
+    reference: DateTimeTuple,
+    with_time: bool,
+) -> Result<DateTimeTuple, Error> {
+    let mut parser = Parser {
+        input,
+        words: split_words(input),
+        index: 0,
+        reference,
+        with_time,
+    };
+    let result = if parser.eat("now") {
+        reference
+    } else if let Some(result) = parser.offset()? {
+        result
+    } else if let Some(date) = parser.date()? {
+        let time = parser.time()?.unwrap_or_else(|| reference.get_time());
+        DateTimeTuple::new(date, time)
+    } else if let Some(time) = parser.time()? {
+        let date = parser.date()?.unwrap_or_else(|| reference.get_date());
+        DateTimeTuple::new(date, time)
+    } else {
+        let expected = if with_time {
+            EXPECTED_DATE_TIME
+        } else {
+            EXPECTED_DATE
+        };
+        return Err(parser.error(parser.position(), expected));
+    };
+    if parser.index < parser.words.len() {
+        return Err(parser.error(parser.position(), "the end of the input"));
+    }
+    Ok(result)
+}
+
+/// Splits the input at whitespace and commas, giving each lowercased word
+/// with its byte offset in the input.
+fn split_words(input: &str) -> Vec<(usize, String)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in input.char_indices().chain(once((input.len(), ' '))) {
+        if c.is_whitespace() || c == ',' {
+            if let Some(start) = start.take() {
+                words.push((start, input[start..i].to_lowercase()));
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    words
+}
+
+/// Moves `time` forwards or backwards by `n` of `unit`, giving `None` if the
+/// result would be out of range.
+fn shift(time: DateTimeTuple, unit: Unit, n: u32, forward: bool) -> Option<DateTimeTuple> {
+    match (unit, forward) {
+        (Unit::Second, true) => time.checked_add_seconds(n),
+        (Unit::Second, false) => time.checked_sub_seconds(n),
+        (Unit::Minute, true) => time.checked_add_minutes(n),
+        (Unit::Minute, false) => time.checked_sub_minutes(n),
+        (Unit::Hour, true) => time.checked_add_hours(n),
+        (Unit::Hour, false) => time.checked_sub_hours(n),
+        (Unit::Day, true) => time.checked_add_days(n),
+        (Unit::Day, false) => time.checked_sub_days(n),
+        (Unit::Week, true) => n.checked_mul(7).and_then(|n| time.checked_add_days(n)),
+        (Unit::Week, false) => n.checked_mul(7).and_then(|n| time.checked_sub_days(n)),
+        (Unit::Month, true) => time.checked_add_months(n),
+        (Unit::Month, false) => time.checked_sub_months(n),
+        (Unit::Year, true) => u16::try_from(n)
+            .ok()
+            .and_then(|n| time.checked_add_years(n)),
+        (Unit::Year, false) => u16::try_from(n)
+            .ok()
+            .and_then(|n| time.checked_sub_years(n)),
+    }
+}
+
+/// Gives the first and last dates of the week (from Monday), month, or year containing `date`.
+fn bounds(date: DateTuple, unit: Unit) -> Result<(DateTuple, DateTuple), Error> {
+    let (y, m) = (date.get_year(), date.get_month());
+    match unit {
+        Unit::Week => {
+            let from_monday = u32::from(date.weekday().number_from_monday()) - 1;
+            Ok((
+                date.saturating_sub_days(from_monday),
+                date.saturating_add_days(6 - from_monday),
+            ))
+        }
+        Unit::Month => Ok((
+            DateTuple::new(y, m, 1)?,
+            DateTuple::new(y, m, date_utils::get_last_date_in_month(m, y))?,
+        )),
+        _ => Ok((DateTuple::new(y, 1, 1)?, DateTuple::new(y, 12, 31)?)),
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    words: Vec<(usize, String)>,
+    index: usize,
+    reference: DateTimeTuple,
+    with_time: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn peek_at(&self, offset: usize) -> Option<&str> {
+        self.words.get(self.index + offset).map(|w| w.1.as_str())
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.peek_at(0)
+    }
+
+    /// Gives the byte offset of the next word, or the end of the input if there are none left.
+    fn position(&self) -> usize {
+        self.words.get(self.index).map_or(self.input.len(), |w| w.0)
+    }
+
+    fn eat(&mut self, word: &str) -> bool {
+        if self.peek() == Some(word) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, word: &str) -> Result<(), Error> {
+        if self.eat(word) {
+            Ok(())
+        } else {
+            Err(self.error(self.position(), &format!("\"{}\"", word)))
+        }
+    }
+
+    fn error(&self, position: usize, expected: &str) -> Error {
+        Error::ParseError {
+            input: self.input.to_string(),
+            position,
+            expected: expected.to_string(),
+        }
+    }
+
+    fn out_of_range(&self) -> Error {
+        let (type_name, min, max) = if self.with_time {
+            (
+                TypeName::DateTimeTuple,
+                DateTimeTuple::min_value().to_string(),
+                DateTimeTuple::max_value().to_string(),
+            )
+        } else {
+            (
+                TypeName::DateTuple,
+                DateTuple::min_value().to_string(),
+                DateTuple::max_value().to_string(),
+            )
+        };
+        Error::OutOfRange {
+            type_name,
+            value: self.input.to_string(),
+            min,
+            max,
+        }
+    }
+
+    /// Reads an offset from the reference, such as "in 2 weeks" or "3 days ago",
+    /// optionally followed by a time of day if the offset is in days or longer.
+    fn offset(&mut self) -> Result<Option<DateTimeTuple>, Error> {
+        let start = self.index;
+        let leading_in = self.eat("in");
+        let n = match self.number() {
+            Some(n) => n,
+            None if leading_in => return Err(self.error(self.position(), "a number")),
+            None => return Ok(None),
+        };
+        let unit = match self.unit() {
+            Some(unit) => unit,
+            None if leading_in => {
+                let expected = if self.with_time {
+                    "a unit such as hours, days, or weeks"
+                } else {
+                    "a unit such as days, weeks, or months"
+                };
+                return Err(self.error(self.position(), expected));
+            }
+            None => {
+                // Something else starting with a number, such as "5 March" or "5 pm".
+                self.index = start;
+                return Ok(None);
+            }
+        };
+        let forward = if leading_in || self.eat("later") {
+            true
+        } else if self.eat("from") {
+            self.expect("now")?;
+            true
+        } else if self.eat("ago") {
+            false
+        } else {
+            return Err(self.error(self.position(), "\"ago\" or \"from now\""));
+        };
+        let mut result =
+            shift(self.reference, unit, n, forward).ok_or_else(|| self.out_of_range())?;
+        if unit >= Unit::Day {
+            if let Some(time) = self.time()? {
+                result = DateTimeTuple::new(result.get_date(), time);
+            }
+        }
+        Ok(Some(result))
+    }
+
+    /// Reads a date, such as "tomorrow", "next Friday", "5th of March", or "end of month".
+    fn date(&mut self) -> Result<Option<DateTuple>, Error> {
+        let today = self.reference.get_date();
+        let on = self.eat("on");
+        let required = self.eat("the") || on;
+        let word = self.peek().unwrap_or("").to_string();
+        let date = match word.as_str() {
+            "today" => {
+                self.index += 1;
+                Some(today)
+            }
+            "tomorrow" => {
+                self.index += 1;
+                today.checked_add_days(1)
+            }
+            "yesterday" => {
+                self.index += 1;
+                today.checked_sub_days(1)
+            }
+            "day" => {
+                self.index += 1;
+                if self.eat("after") {
+                    self.expect("tomorrow")?;
+                    today.checked_add_days(2)
+                } else if self.eat("before") {
+                    self.expect("yesterday")?;
+                    today.checked_sub_days(2)
+                } else {
+                    let expected = "\"after tomorrow\" or \"before yesterday\"";
+                    return Err(self.error(self.position(), expected));
+                }
+            }
+            "first" | "last" if self.peek_at(1) == Some("day") => {
+                self.index += 2;
+                self.expect("of")?;
+                let (first, last) = self.period()?;
+                Some(if word == "first" { first } else { last })
+            }
+            "start" | "beginning" | "end" => {
+                self.index += 1;
+                self.expect("of")?;
+                let (first, last) = self.period()?;
+                Some(if word == "end" { last } else { first })
+            }
+            "this" | "next" | "last" => {
+                self.index += 1;
+                if let Some(weekday) = self.weekday() {
+                    self.weekday_from_today(weekday, &word)
+                } else if let Some(unit) = self.period_unit() {
+                    match word.as_str() {
+                        "this" => Some(today),
+                        _ => shift(self.reference, unit, 1, word == "next").map(|t| t.get_date()),
+                    }
+                } else {
+                    let expected = "a weekday, week, month, or year";
+                    return Err(self.error(self.position(), expected));
+                }
+            }
+            _ => {
+                if let Some(weekday) = self.weekday() {
+                    self.weekday_from_today(weekday, "this")
+                } else if let Some(date) = self.month_day()? {
+                    Some(date)
+                } else if required {
+                    return Err(self.error(self.position(), EXPECTED_DATE));
+                } else {
+                    return Ok(None);
+                }
+            }
+        };
+        date.map(Some).ok_or_else(|| self.out_of_range())
+    }
+
+    /// Finds the given weekday from today: on or after it for "this",
+    /// after it for "next", and before it for "last".
+    fn weekday_from_today(&self, weekday: Weekday, which: &str) -> Option<DateTuple> {
+        let today = self.reference.get_date();
+        let ahead = (7 + weekday as u32 - today.weekday() as u32) % 7;
+        match which {
+            "this" => today.checked_add_days(ahead),
+            "next" => today.checked_add_days(if ahead == 0 { 7 } else { ahead }),
+            _ => today.checked_sub_days(7 - ahead),
+        }
+    }
+
+    /// Reads a week, month, or year, such as "next month", "the year", or
+    /// "February 2019", giving its first and last dates.
+    ///
+    /// A month name without a year is the next such month, counting the current one.
+    fn period(&mut self) -> Result<(DateTuple, DateTuple), Error> {
+        self.eat("the");
+        let today = self.reference.get_date();
+        let which = match self.peek() {
+            Some("this") | Some("next") | Some("last") => {
+                self.index += 1;
+                self.words[self.index - 1].1.clone()
+            }
+            _ => String::from("this"),
+        };
+        if let Some(unit) = self.period_unit() {
+            let date = match which.as_str() {
+                "this" => Some(today),
+                _ => shift(self.reference, unit, 1, which == "next").map(|t| t.get_date()),
+            };
+            return bounds(date.ok_or_else(|| self.out_of_range())?, unit);
+        }
+        if which == "this" {
+            if let Some(m) = self.month_name() {
+                let y = match self.year() {
+                    Some(y) => y,
+                    None if m >= today.get_month() => today.get_year(),
+                    None => today.get_year() + 1,
+                };
+                return bounds(DateTuple::new(y, m, 1)?, Unit::Month);
+            }
+        }
+        let expected = "a week, month, year, or month name";
+        Err(self.error(self.position(), expected))
+    }
+
+    /// Reads a day and month in either order, such as "March 5th" or "5 March 2019".
+    ///
+    /// Without a year, this is the next such date, counting today.
+    fn month_day(&mut self) -> Result<Option<DateTuple>, Error> {
+        let start = self.index;
+        let (m, d) = if let Some(m) = self.month_name() {
+            match self.ordinal() {
+                Some(d) => (m, d),
+                None => return Err(self.error(self.position(), "a day of the month")),
+            }
+        } else if let Some(d) = self.ordinal() {
+            self.eat("of");
+            match self.month_name() {
+                Some(m) => (m, d),
+                None => {
+                    self.index = start;
+                    return Ok(None);
+                }
+            }
+        } else {
+            return Ok(None);
+        };
+        let today = self.reference.get_date();
+        let y = match self.year() {
+            Some(y) => y,
+            None if (m, d) >= (today.get_month(), today.get_date()) => today.get_year(),
+            None => today.get_year() + 1,
+        };
+        DateTuple::new(y, m, d).map(Some)
+    }
+
+    /// Reads a time of day, such as "5pm", "at 17:30", or "noon".
+    ///
+    /// A bare number is only taken as an hour after "at".
+    fn time(&mut self) -> Result<Option<TimeTuple>, Error> {
+        if !self.with_time {
+            return Ok(None);
+        }
+        let at = self.eat("at");
+        let position = self.position();
+        let word = match self.peek() {
+            Some(word) => word.to_string(),
+            None if at => return Err(self.error(position, EXPECTED_TIME)),
+            None => return Ok(None),
+        };
+        let time = match word.as_str() {
+            "noon" | "midday" => TimeTuple::new(12, 0, 0),
+            "midnight" => TimeTuple::new(0, 0, 0),
+            _ => {
+                let caps = match TIME.captures(&word) {
+                    Some(caps) => caps,
+                    None if at => return Err(self.error(position, EXPECTED_TIME)),
+                    None => return Ok(None),
+                };
+                let hour = i32::from_str(&caps[1]).unwrap();
+                let minute = caps
+                    .get(2)
+                    .map_or(0, |m| i32::from_str(m.as_str()).unwrap());
+                let mut pm = caps.get(3).map(|m| m.as_str().starts_with('p'));
+                if pm.is_none() {
+                    if let Some(next) = self.peek_at(1) {
+                        if let "am" | "pm" | "a.m." | "p.m." = next {
+                            pm = Some(next.starts_with('p'));
+                            self.index += 1;
+                        }
+                    }
+                }
+                let hour = match pm {
+                    Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+                    Some(_) => return Err(self.error(position, "an hour between 1 and 12")),
+                    None if !at && caps.get(2).is_none() => return Ok(None),
+                    None if hour <= 23 => hour,
+                    None => return Err(self.error(position, "an hour between 0 and 23")),
+                };
+                if minute > 59 {
+                    return Err(self.error(position, "minutes between 00 and 59"));
+                }
+                TimeTuple::new(hour, minute, 0)
+            }
+        };
+        self.index += 1;
+        Ok(Some(time))
+    }
+
+    /// Reads a number in digits or a small number in words, including "a" for one.
+    fn number(&mut self) -> Option<u32> {
+        let n = match self.peek()? {
+            "a" | "an" | "one" => 1,
+            "two" => 2,
+            "three" => 3,
+            "four" => 4,
+            "five" => 5,
+            "six" => 6,
+            "seven" => 7,
+            "eight" => 8,
+            "nine" => 9,
+            "ten" => 10,
+            word if word.bytes().all(|b| b.is_ascii_digit()) => {
+                // Numbers too large for a u32 are too large for any offset as well.
+                u32::from_str(word).unwrap_or(u32::MAX)
+            }
+            _ => return None,
+        };
+        self.index += 1;
+        Some(n)
+    }
+
+    fn unit(&mut self) -> Option<Unit> {
+        let unit = match self.peek()? {
+            "second" | "seconds" | "sec" | "secs" if self.with_time => Unit::Second,
+            "minute" | "minutes" | "min" | "mins" if self.with_time => Unit::Minute,
+            "hour" | "hours" | "hr" | "hrs" if self.with_time => Unit::Hour,
+            "day" | "days" => Unit::Day,
+            "week" | "weeks" => Unit::Week,
+            "month" | "months" => Unit::Month,
+            "year" | "years" => Unit::Year,
+            _ => return None,
+        };
+        self.index += 1;
+        Some(unit)
+    }
+
+    fn period_unit(&mut self) -> Option<Unit> {
+        let unit = match self.peek()? {
+            "week" => Unit::Week,
+            "month" => Unit::Month,
+            "year" => Unit::Year,
+            _ => return None,
+        };
+        self.index += 1;
+        Some(unit)
+    }
+
+    fn weekday(&mut self) -> Option<Weekday> {
+        let word = self.peek()?;
+        let i = WEEKDAY_STRINGS.iter().position(|name| {
+            name.eq_ignore_ascii_case(word) || name[..3].eq_ignore_ascii_case(word)
+        })?;
+        self.index += 1;
+        Some(Weekday::from_days_from_monday(i as u32))
+    }
+
+    fn month_name(&mut self) -> Option<u8> {
+        let word = self.peek()?;
+        let i = MONTH_NAMES
+            .iter()
+            .zip(MONTH_STRINGS.iter())
+            .position(|(name, short)| {
+                name.eq_ignore_ascii_case(word) || short.eq_ignore_ascii_case(word)
+            })?;
+        self.index += 1;
+        Some(i as u8 + 1)
+    }
+
+    /// Reads a day of the month, such as "5" or "5th".
+    fn ordinal(&mut self) -> Option<u8> {
+        let word = self.peek()?;
+        let digits = word.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 || digits > 2 {
+            return None;
+        }
+        if let "" | "st" | "nd" | "rd" | "th" = &word[digits..] {
+            let d = u8::from_str(&word[..digits]).unwrap();
+            self.index += 1;
+            Some(d)
+        } else {
+            None
+        }
+    }
+
+    fn year(&mut self) -> Option<u16> {
+        let word = self.peek()?;
+        if word.len() != 4 || !word.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let y = u16::from_str(word).unwrap();
+        self.index += 1;
+        Some(y)
+    }
+}
@@ -0,0 +1,178 @@
+use date_time_tuple::DateTimeTuple;
+use date_tuple::DateTuple;
+use error::{Error, TypeName};
+use std::cmp::Ordering;
+#[cfg(feature = "serde_support")]
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use time_tuple::TimeTuple;
+use utc_offset::UtcOffset;
+
+/// A date and time on the local clock at a fixed offset from UTC.
+///
+/// Both the local date and time and the same instant in UTC are always within the
+/// range of `DateTimeTuple`.
+///
+/// Two OffsetDateTimes are compared by the instant they represent rather than by
+/// their local clocks, so 10:00+02:00 is equal to 08:00Z.
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "OffsetDateTimeFields")
+)]
+#[derive(Debug, Copy, Clone)]
+pub struct OffsetDateTime {
+    dt: DateTimeTuple,
+    offset: UtcOffset,
+}
+
+/// The fields of a serialised OffsetDateTime, which are checked before use.
+#[cfg(feature = "serde_support")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "OffsetDateTime")]
+struct OffsetDateTimeFields {
+    dt: DateTimeTuple,
+    offset: UtcOffset,
+}
+
+#[cfg(feature = "serde_support")]
+impl TryFrom<OffsetDateTimeFields> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(fields: OffsetDateTimeFields) -> Result<OffsetDateTime, Error> {
+        OffsetDateTime::new(fields.dt, fields.offset)
+    }
+}
+
+impl OffsetDateTime {
+    /// Produces an OffsetDateTime from the local date and time and its offset from UTC.
+    ///
+    /// Fails with `Error::OutOfRange` if the same instant in UTC would fall outside
+    /// of the range of `DateTimeTuple`.
+    pub fn new(local: DateTimeTuple, offset: UtcOffset) -> Result<OffsetDateTime, Error> {
+        let result = OffsetDateTime { dt: local, offset };
+        match local.checked_add_signed_seconds(-i64::from(offset.to_seconds())) {
+            Some(_) => Ok(result),
+            None => Err(result.out_of_range()),
+        }
+    }
+
+    /// Produces an OffsetDateTime for a date and time in UTC, as seen at the given offset.
+    ///
+    /// Fails with `Error::OutOfRange` if the local date and time would fall outside
+    /// of the range of `DateTimeTuple`.
+    pub fn from_utc(utc: DateTimeTuple, offset: UtcOffset) -> Result<OffsetDateTime, Error> {
+        match utc.checked_add_signed_seconds(i64::from(offset.to_seconds())) {
+            Some(dt) => Ok(OffsetDateTime { dt, offset }),
+            None => Err(OffsetDateTime {
+                dt: utc,
+                offset: UtcOffset::utc(),
+            }
+            .out_of_range()),
+        }
+    }
+
+    /// Gets the date and time on the local clock.
+    pub fn get_date_time(self) -> DateTimeTuple {
+        self.dt
+    }
+
+    /// Gets the date on the local clock.
+    pub fn get_date(self) -> DateTuple {
+        self.dt.get_date()
+    }
+
+    /// Gets the time on the local clock.
+    pub fn get_time(self) -> TimeTuple {
+        self.dt.get_time()
+    }
+
+    pub fn get_offset(self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Gets the same instant as a date and time in UTC.
+    pub fn to_utc(self) -> DateTimeTuple {
+        self.dt
+            .checked_add_signed_seconds(-i64::from(self.offset.to_seconds()))
+            .unwrap()
+    }
+
+    /// Gets the same instant as seen at a different offset.
+    ///
+    /// Fails with `Error::OutOfRange` if the local date and time at that offset would
+    /// fall outside of the range of `DateTimeTuple`.
+    pub fn to_offset(self, offset: UtcOffset) -> Result<OffsetDateTime, Error> {
+        OffsetDateTime::from_utc(self.to_utc(), offset)
+    }
+
+    /// Gets the number of seconds from `DateTimeTuple::min_value()` to the instant in UTC.
+    fn instant(self) -> i64 {
+        self.dt.to_seconds() as i64 - i64::from(self.offset.to_seconds())
+    }
+
+    fn out_of_range(self) -> Error {
+        Error::OutOfRange {
+            type_name: TypeName::OffsetDateTime,
+            value: self.to_string(),
+            min: DateTimeTuple::min_value().to_rfc3339(),
+            max: DateTimeTuple::max_value().to_rfc3339(),
+        }
+    }
+}
+
+impl fmt::Display for OffsetDateTime {
+    /// Formats the date and time in RFC 3339 form, like 2018-10-02T10:30:00+02:00,
+    /// using Z for an offset of zero.
+    ///
+    /// RFC 3339 has no seconds in offsets, so an offset with seconds, which is only
+    /// found in historical time zone data, is written as +00:19:32.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}T{}", self.dt.get_date(), self.dt.get_time())?;
+        if self.offset.is_utc() {
+            write!(f, "Z")
+        } else {
+            write!(f, "{}", self.offset)
+        }
+    }
+}
+
+impl FromStr for OffsetDateTime {
+    type Err = Error;
+
+    /// Expects an RFC 3339 timestamp such as 2018-10-02T10:30:00+02:00, which is read
+    /// as `DateTimeTuple::from_rfc3339_with_offset()` reads it.
+    fn from_str(s: &str) -> Result<OffsetDateTime, Self::Err> {
+        let (local, offset) = DateTimeTuple::from_rfc3339_with_offset(s)?;
+        OffsetDateTime::new(local, UtcOffset::from_seconds(offset)?)
+    }
+}
+
+impl PartialEq for OffsetDateTime {
+    fn eq(&self, other: &OffsetDateTime) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for OffsetDateTime {}
+
+impl Hash for OffsetDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
+}
+
+impl PartialOrd for OffsetDateTime {
+    fn partial_cmp(&self, other: &OffsetDateTime) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for OffsetDateTime {
+    fn cmp(&self, other: &OffsetDateTime) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
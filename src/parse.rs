@@ -0,0 +1,451 @@
+use date_time_tuple::DateTimeTuple;
+use date_tuple::DateTuple;
+use error::{Error, TypeName};
+use format::{Directive, Item, Pattern};
+use locale::Locale;
+use month_tuple::MonthTuple;
+use quarter_tuple::QuarterTuple;
+use std::convert::TryFrom;
+use time_tuple::{Duration, TimeTuple};
+use week_tuple::WeekTuple;
+use weekday::Weekday;
+
+/// A component of a date or time which can be read from the input.
+///
+/// Several directives can produce the same field, such as `%b` and `%m`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum Field {
+    Year,
+    YearOfCentury,
+    Month,
+    Quarter,
+    Day,
+    DayOfYear,
+    Weekday,
+    IsoWeek,
+    IsoWeekYear,
+    Hour,
+    Hour12,
+    AmPm,
+    Minute,
+    Second,
+}
+
+const FIELD_COUNT: usize = 14;
+
+impl Field {
+    fn of(directive: Directive) -> Field {
+        match directive {
+            Directive::Year => Field::Year,
+            Directive::YearOfCentury => Field::YearOfCentury,
+            Directive::Month | Directive::MonthShortName | Directive::MonthName => Field::Month,
+            Directive::Quarter => Field::Quarter,
+            Directive::Day => Field::Day,
+            Directive::DayOfYear => Field::DayOfYear,
+            Directive::WeekdayShortName
+            | Directive::WeekdayName
+            | Directive::WeekdayFromMonday
+            | Directive::WeekdayFromSunday => Field::Weekday,
+            Directive::IsoWeek => Field::IsoWeek,
+            Directive::IsoWeekYear => Field::IsoWeekYear,
+            Directive::Hour => Field::Hour,
+            Directive::Hour12 => Field::Hour12,
+            Directive::AmPm => Field::AmPm,
+            Directive::Minute => Field::Minute,
+            Directive::Second => Field::Second,
+        }
+    }
+}
+
+/// The type being parsed, which determines the directives a pattern may contain.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub(crate) enum Kind {
+    Date,
+    Month,
+    Time,
+    DateTime,
+    Duration,
+}
+
+impl Kind {
+    fn type_name(self) -> TypeName {
+        match self {
+            Kind::Date => TypeName::DateTuple,
+            Kind::Month => TypeName::MonthTuple,
+            Kind::Time => TypeName::TimeTuple,
+            Kind::DateTime => TypeName::DateTimeTuple,
+            Kind::Duration => TypeName::Duration,
+        }
+    }
+
+    fn supports(self, field: Field) -> bool {
+        match field {
+            Field::Year | Field::YearOfCentury | Field::Month | Field::Quarter => {
+                matches!(self, Kind::Date | Kind::Month | Kind::DateTime)
+            }
+            Field::Day
+            | Field::DayOfYear
+            | Field::Weekday
+            | Field::IsoWeek
+            | Field::IsoWeekYear => {
+                matches!(self, Kind::Date | Kind::DateTime)
+            }
+            Field::Hour | Field::Minute | Field::Second => {
+                matches!(self, Kind::Time | Kind::DateTime | Kind::Duration)
+            }
+            Field::Hour12 | Field::AmPm => matches!(self, Kind::Time | Kind::DateTime),
+        }
+    }
+}
+
+/// Gets a description of the value a directive reads, for use in error messages.
+fn describe(directive: Directive) -> &'static str {
+    match directive {
+        Directive::Year => "a year",
+        Directive::YearOfCentury => "a year within the century",
+        Directive::Month => "a month",
+        Directive::MonthShortName => "an abbreviated month name",
+        Directive::MonthName => "a month name",
+        Directive::Quarter => "a quarter",
+        Directive::Day => "a day of the month",
+        Directive::DayOfYear => "a day of the year",
+        Directive::WeekdayShortName => "an abbreviated weekday name",
+        Directive::WeekdayName => "a weekday name",
+        Directive::WeekdayFromMonday | Directive::WeekdayFromSunday => "a weekday number",
+        Directive::IsoWeek => "an ISO week number",
+        Directive::IsoWeekYear => "an ISO week-numbering year",
+        Directive::Hour => "an hour",
+        Directive::Hour12 => "an hour on a 12-hour clock",
+        Directive::AmPm => "AM or PM",
+        Directive::Minute => "a minute",
+        Directive::Second => "a second",
+    }
+}
+
+/// Gets the range of values a numeric directive accepts.
+fn range(directive: Directive, kind: Kind) -> (u64, u64) {
+    match directive {
+        Directive::Year | Directive::IsoWeekYear => (0, 9999),
+        Directive::YearOfCentury => (0, 99),
+        Directive::Month => (1, 12),
+        Directive::Quarter => (1, 4),
+        Directive::Day => (1, 31),
+        Directive::DayOfYear => (1, 366),
+        Directive::WeekdayFromMonday => (1, 7),
+        Directive::WeekdayFromSunday => (0, 6),
+        Directive::IsoWeek => (1, 53),
+        Directive::Hour if kind == Kind::Duration => (0, u64::from(u32::MAX)),
+        Directive::Hour => (0, 23),
+        Directive::Hour12 => (1, 12),
+        _ => (0, 59),
+    }
+}
+
+/// Finds which of `names` the input starts with, ignoring case and preferring the
+/// longest match, since in some locales one name begins with another.
+///
+/// Returns the index of the name and its length.
+fn read_name<'a, I: Iterator<Item = &'a str>>(input: &str, names: I) -> Option<(u32, usize)> {
+    let mut best: Option<(u32, usize)> = None;
+    for (i, name) in names.enumerate() {
+        if let Some((_, length)) = best {
+            if name.len() <= length {
+                continue;
+            }
+        }
+        if let Some(prefix) = input.get(..name.len()) {
+            if prefix.to_lowercase() == name.to_lowercase() {
+                best = Some((i as u32, name.len()));
+            }
+        }
+    }
+    best
+}
+
+/// The fields read from an input string, each with the byte offset at which it was read.
+pub(crate) struct Parsed<'a> {
+    input: &'a str,
+    kind: Kind,
+    locale: &'a Locale,
+    fields: [Option<(u32, usize)>; FIELD_COUNT],
+}
+
+/// Reads the input according to a pattern.
+///
+/// Fails if the input doesn't match the pattern, or if the pattern contains a directive
+/// which `kind` doesn't hold.
+pub(crate) fn parse<'a>(
+    input: &'a str,
+    pattern: &'a Pattern,
+    kind: Kind,
+) -> Result<Parsed<'a>, Error> {
+    for item in pattern.items() {
+        if let Item::Directive { directive, .. } = item {
+            if !kind.supports(Field::of(*directive)) {
+                return Err(Error::UnsupportedDirective {
+                    type_name: kind.type_name(),
+                    directive: directive.to_char(),
+                });
+            }
+        }
+    }
+    let mut parsed = Parsed {
+        input,
+        kind,
+        locale: pattern.get_locale(),
+        fields: [None; FIELD_COUNT],
+    };
+    let mut position = 0;
+    for item in pattern.items() {
+        match item {
+            Item::Literal(literal) => {
+                if !input[position..].starts_with(literal.as_str()) {
+                    return Err(parsed.error(position, format!("\"{}\"", literal)));
+                }
+                position += literal.len();
+            }
+            Item::Directive { directive, padded } => {
+                let (value, length) = parsed.read(position, *directive, *padded)?;
+                parsed.set(Field::of(*directive), value, position)?;
+                position += length;
+            }
+        }
+    }
+    if position < input.len() {
+        return Err(parsed.error(position, "the end of the input".to_string()));
+    }
+    Ok(parsed)
+}
+
+impl<'a> Parsed<'a> {
+    fn error(&self, position: usize, expected: String) -> Error {
+        Error::ParseError {
+            input: self.input.to_string(),
+            position,
+            expected,
+        }
+    }
+
+    fn incomplete(&self, missing: &'static str) -> Error {
+        Error::IncompletePattern {
+            type_name: self.kind.type_name(),
+            missing,
+        }
+    }
+
+    /// Reads a single directive at `position`, giving its value and the number of bytes it took up.
+    fn read(
+        &self,
+        position: usize,
+        directive: Directive,
+        padded: bool,
+    ) -> Result<(u32, usize), Error> {
+        let rest = &self.input[position..];
+        let name = match directive {
+            Directive::MonthShortName => Some(read_name(
+                rest,
+                self.locale
+                    .short_month_names()
+                    .iter()
+                    .map(|name| name.as_ref()),
+            )),
+            Directive::MonthName => Some(read_name(
+                rest,
+                self.locale.month_names().iter().map(|name| name.as_ref()),
+            )),
+            Directive::WeekdayShortName => Some(read_name(
+                rest,
+                self.locale
+                    .short_weekday_names()
+                    .iter()
+                    .map(|name| name.as_ref()),
+            )),
+            Directive::WeekdayName => Some(read_name(
+                rest,
+                self.locale.weekday_names().iter().map(|name| name.as_ref()),
+            )),
+            Directive::AmPm => {
+                return read_name(
+                    rest,
+                    self.locale.am_pm_markers().iter().map(|name| name.as_ref()),
+                )
+                .ok_or_else(|| self.error(position, describe(directive).to_string()))
+            }
+            _ => None,
+        };
+        if let Some(name) = name {
+            // Months and weekdays are both numbered from one.
+            return name
+                .map(|(i, length)| (i + 1, length))
+                .ok_or_else(|| self.error(position, describe(directive).to_string()));
+        }
+
+        let width = directive.width().unwrap();
+        let (min, max) = range(directive, self.kind);
+        let max_digits = if directive == Directive::Hour && self.kind == Kind::Duration {
+            10
+        } else {
+            width
+        };
+        let digits = rest
+            .bytes()
+            .take(max_digits)
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if digits == 0 || (padded && digits < width) {
+            return Err(self.error(
+                position,
+                if padded {
+                    format!("{} of {} digits", describe(directive), width)
+                } else {
+                    describe(directive).to_string()
+                },
+            ));
+        }
+        let value: u64 = rest[..digits].parse().unwrap();
+        if value < min || value > max {
+            return Err(self.error(
+                position,
+                format!("{} between {} and {}", describe(directive), min, max),
+            ));
+        }
+        let value = match directive {
+            Directive::WeekdayFromSunday if value == 0 => 7,
+            _ => value as u32,
+        };
+        Ok((value, digits))
+    }
+
+    /// Records the value of a field, failing if it was already read with a different value.
+    fn set(&mut self, field: Field, value: u32, position: usize) -> Result<(), Error> {
+        match self.fields[field as usize] {
+            Some((existing, _)) if existing != value => Err(self.error(
+                position,
+                "the same value as earlier in the input".to_string(),
+            )),
+            _ => {
+                self.fields[field as usize] = Some((value, position));
+                Ok(())
+            }
+        }
+    }
+
+    fn get(&self, field: Field) -> Option<u32> {
+        self.fields[field as usize].map(|(value, _)| value)
+    }
+
+    /// Checks that a field which was read agrees with the value it should have.
+    fn check(&self, field: Field, actual: u32) -> Result<(), Error> {
+        match self.fields[field as usize] {
+            Some((value, position)) if value != actual => {
+                let shown = match field {
+                    Field::Weekday => self.locale.weekday_names()[actual as usize - 1].to_string(),
+                    Field::AmPm => self.locale.am_pm_markers()[actual as usize].to_string(),
+                    _ => actual.to_string(),
+                };
+                Err(self.error(
+                    position,
+                    format!("{} to agree with the rest of the input", shown),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Gets the year, interpreting `%y` as 1969 to 2068 when there is no `%Y`.
+    fn year(&self) -> Option<u16> {
+        match (self.get(Field::Year), self.get(Field::YearOfCentury)) {
+            (Some(y), _) => Some(y as u16),
+            (None, Some(y)) if y < 69 => Some(2000 + y as u16),
+            (None, Some(y)) => Some(1900 + y as u16),
+            (None, None) => None,
+        }
+    }
+
+    pub(crate) fn to_date(&self) -> Result<DateTuple, Error> {
+        let year = self.year();
+        let date = match (year, self.get(Field::Month), self.get(Field::Day)) {
+            (Some(y), Some(m), Some(d)) => DateTuple::new(y, m as u8, d as u8)?,
+            _ => match (year, self.get(Field::DayOfYear)) {
+                (Some(y), Some(day)) => DateTuple::from_ordinal(y, day as u16)?,
+                _ => match (
+                    self.get(Field::IsoWeekYear),
+                    self.get(Field::IsoWeek),
+                    self.get(Field::Weekday),
+                ) {
+                    (Some(y), Some(w), Some(wd)) => WeekTuple::new(y as u16, w as u8)?
+                        .to_date(Weekday::from_number_from_monday(wd as u8)?)?,
+                    _ if year.is_none() => return Err(self.incomplete("year")),
+                    _ if self.get(Field::Month).is_none() => return Err(self.incomplete("month")),
+                    _ => return Err(self.incomplete("day")),
+                },
+            },
+        };
+        self.check(Field::Year, u32::from(date.get_year()))?;
+        self.check(Field::YearOfCentury, u32::from(date.get_year() % 100))?;
+        self.check(Field::Month, u32::from(date.get_month()))?;
+        self.check(Field::Day, u32::from(date.get_date()))?;
+        self.check(Field::DayOfYear, u32::from(date.day_of_year()))?;
+        self.check(
+            Field::Quarter,
+            u32::from(QuarterTuple::from(date).get_quarter()),
+        )?;
+        self.check(
+            Field::Weekday,
+            u32::from(date.weekday().number_from_monday()),
+        )?;
+        if self.get(Field::IsoWeek).is_some() || self.get(Field::IsoWeekYear).is_some() {
+            let week = WeekTuple::try_from(date)?;
+            self.check(Field::IsoWeek, u32::from(week.get_week()))?;
+            self.check(Field::IsoWeekYear, u32::from(week.get_year()))?;
+        }
+        Ok(date)
+    }
+
+    pub(crate) fn to_month(&self) -> Result<MonthTuple, Error> {
+        let year = self.year().ok_or_else(|| self.incomplete("year"))?;
+        let month = self
+            .get(Field::Month)
+            .ok_or_else(|| self.incomplete("month"))?;
+        let month = MonthTuple::new(year, month as u8)?;
+        self.check(Field::YearOfCentury, u32::from(year % 100))?;
+        self.check(
+            Field::Quarter,
+            u32::from(QuarterTuple::from(month).get_quarter()),
+        )?;
+        Ok(month)
+    }
+
+    /// Gets the hour of the day, reading `%I` without `%p` as a morning hour.
+    fn hour_of_day(&self) -> Result<u32, Error> {
+        let hour = match (self.get(Field::Hour), self.get(Field::Hour12)) {
+            (Some(h), _) => h,
+            (None, Some(h)) => h % 12 + 12 * self.get(Field::AmPm).unwrap_or(0),
+            (None, None) => return Err(self.incomplete("hour")),
+        };
+        self.check(Field::Hour12, if hour % 12 == 0 { 12 } else { hour % 12 })?;
+        self.check(Field::AmPm, if hour < 12 { 0 } else { 1 })?;
+        Ok(hour)
+    }
+
+    pub(crate) fn to_time(&self) -> Result<TimeTuple, Error> {
+        Ok(TimeTuple::new(
+            self.hour_of_day()? as i32,
+            self.get(Field::Minute).unwrap_or(0) as i32,
+            self.get(Field::Second).unwrap_or(0) as i32,
+        ))
+    }
+
+    pub(crate) fn to_date_time(&self) -> Result<DateTimeTuple, Error> {
+        Ok(DateTimeTuple::new(self.to_date()?, self.to_time()?))
+    }
+
+    pub(crate) fn to_duration(&self) -> Result<Duration, Error> {
+        Ok(Duration::new(
+            self.get(Field::Hour)
+                .ok_or_else(|| self.incomplete("hour"))?,
+            self.get(Field::Minute).unwrap_or(0),
+            self.get(Field::Second).unwrap_or(0),
+        ))
+    }
+}
@@ -0,0 +1,418 @@
+//! Time zone rules in the form of the POSIX `TZ` environment variable.
+//!
+//! A POSIX TZ string names a standard time and its offset, optionally followed by a
+//! daylight saving time and the rules for when it starts and ends each year:
+//!
+//! * `JST-9` is nine hours east of UTC all year round.
+//! * `CET-1CEST,M3.5.0,M10.5.0/3` is Central European Time, with summer time from
+//!   02:00 on the last Sunday in March until 03:00 on the last Sunday in October.
+//! * `<-03>3` uses an abbreviation in angle brackets, as it is not alphabetic.
+//!
+//! Offsets are hours west of UTC, the opposite of ISO 8601, and summer time is an hour
+//! ahead of standard time unless its offset is given. Rule dates may be `Mm.w.d` (day
+//! `d` of week `w` of month `m`, where day 0 is Sunday and week 5 is the last), `Jn`
+//! (day `n` of the year from 1, never counting 29th February), or `n` (day `n` of the
+//! year from 0). Rule times are local, default to 02:00, and may be negative or up to
+//! 167 hours, as TZif version 3 allows. Without rules, the United States' rules from
+//! 2007 are assumed.
+//!
+//! These strings are found at the end of TZif files, where they describe times after
+//! the last transition, and can be used on their own through `TimeZone::from_posix_tz()`.
+
+use date_time_tuple::DateTimeTuple;
+use date_utils::{days_from_civil, is_leap_year};
+use error::Error;
+use std::fmt;
+use std::str::FromStr;
+use tz::LocalTimeType;
+use utc_offset::UtcOffset;
+
+const SECONDS_IN_A_DAY: i64 = 86400;
+
+/// The rules assumed when daylight saving time is named without any.
+const DEFAULT_RULES: [TransitionRule; 2] = [
+    TransitionRule {
+        date: RuleDate::MonthWeekDay {
+            month: 3,
+            week: 2,
+            weekday: 0,
+        },
+        time: 7200,
+    },
+    TransitionRule {
+        date: RuleDate::MonthWeekDay {
+            month: 11,
+            week: 1,
+            weekday: 0,
+        },
+        time: 7200,
+    },
+];
+
+/// The day of the year on which a transition happens.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+enum RuleDate {
+    /// `Jn`: day 1 to 365, never counting 29th February.
+    Julian(u16),
+    /// `n`: day 0 to 365, counting 29th February.
+    Ordinal(u16),
+    /// `Mm.w.d`: the given weekday, from 0 for Sunday, in week 1 to 5 of the month,
+    /// where week 5 is the last.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+struct TransitionRule {
+    date: RuleDate,
+    /// Seconds after local midnight, which may be negative or more than a day.
+    time: i32,
+}
+
+impl TransitionRule {
+    /// Gets the instant of this transition in a year, in Unix seconds, for a
+    /// rule given in local time at the offset in effect before it.
+    fn instant_in(self, year: i64, offset: UtcOffset) -> i64 {
+        let new_year = days_from_civil(year, 1, 1);
+        let day = match self.date {
+            RuleDate::Julian(n) => {
+                let n = i64::from(n);
+                new_year + n - 1 + if is_leap_year(year) && n >= 60 { 1 } else { 0 }
+            }
+            RuleDate::Ordinal(n) => new_year + i64::from(n),
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                // 1st January 1970 was a Thursday, which is day 4 counting from Sunday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first + (i64::from(weekday) - first_weekday).rem_euclid(7);
+                day += 7 * (i64::from(week) - 1);
+                let next_month = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                while day >= next_month {
+                    day -= 7;
+                }
+                day
+            }
+        };
+        day * SECONDS_IN_A_DAY + i64::from(self.time) - i64::from(offset.to_seconds())
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+struct DaylightSaving {
+    local_time_type: LocalTimeType,
+    start: TransitionRule,
+    end: TransitionRule,
+}
+
+/// Time zone rules parsed from a POSIX TZ string, such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+///
+/// These give the offset from UTC and whether daylight saving time is in effect at
+/// any instant, with no need for a zoneinfo database.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct PosixTz {
+    text: String,
+    standard: LocalTimeType,
+    daylight_saving: Option<DaylightSaving>,
+}
+
+impl PosixTz {
+    /// Gets standard time, as named first in the string.
+    pub fn get_standard(&self) -> &LocalTimeType {
+        &self.standard
+    }
+
+    /// Gets daylight saving time, if the string names one.
+    pub fn get_daylight_saving(&self) -> Option<&LocalTimeType> {
+        self.daylight_saving
+            .as_ref()
+            .map(|dst| &dst.local_time_type)
+    }
+
+    /// Gets the local time type in effect at an instant in UTC.
+    pub fn local_time_type_at(&self, utc: DateTimeTuple) -> &LocalTimeType {
+        self.local_time_type_at_unix(utc.to_unix_seconds())
+    }
+
+    /// Gets the local time type in effect at an instant given in Unix seconds.
+    pub(crate) fn local_time_type_at_unix(&self, instant: i64) -> &LocalTimeType {
+        let year = year_of(instant);
+        let is_dst = self
+            .transitions_in_years(year - 1, year + 1)
+            .into_iter()
+            .take_while(|&(at, _)| at <= instant)
+            .last()
+            .is_some_and(|(_, is_dst)| is_dst);
+        self.local_time_type(is_dst)
+    }
+
+    /// Gets the transitions after one instant and up to and including another, given
+    /// in Unix seconds, along with the local time type each one changes to.
+    pub(crate) fn transitions_between(&self, from: i64, to: i64) -> Vec<(i64, &LocalTimeType)> {
+        let mut is_dst = self.local_time_type_at_unix(from).is_dst();
+        let all = self.transitions_in_years(year_of(from) - 1, year_of(to) + 1);
+        let mut transitions = Vec::new();
+        for (i, &(at, to_dst)) in all.iter().enumerate() {
+            // Only the last of several transitions at the same instant takes effect.
+            let superseded = all.get(i + 1).is_some_and(|&(next, _)| next == at);
+            if at > from && at <= to && !superseded && to_dst != is_dst {
+                transitions.push((at, self.local_time_type(to_dst)));
+                is_dst = to_dst;
+            }
+        }
+        transitions
+    }
+
+    /// Gets the instants at which daylight saving time starts (`true`) and ends
+    /// (`false`) in a range of years, in order. Where both happen at once, the end
+    /// comes first, so that daylight saving time can last all year.
+    fn transitions_in_years(&self, first: i64, last: i64) -> Vec<(i64, bool)> {
+        let dst = match self.daylight_saving {
+            Some(ref dst) => dst,
+            None => return Vec::new(),
+        };
+        let standard_offset = self.standard.get_offset();
+        let dst_offset = dst.local_time_type.get_offset();
+        let mut transitions: Vec<(i64, bool)> = (first..=last)
+            .flat_map(|year| {
+                vec![
+                    (dst.start.instant_in(year, standard_offset), true),
+                    (dst.end.instant_in(year, dst_offset), false),
+                ]
+            })
+            .collect();
+        transitions.sort();
+        transitions
+    }
+
+    fn local_time_type(&self, is_dst: bool) -> &LocalTimeType {
+        match self.daylight_saving {
+            Some(ref dst) if is_dst => &dst.local_time_type,
+            _ => &self.standard,
+        }
+    }
+}
+
+impl fmt::Display for PosixTz {
+    /// Formats the rules as the string they were parsed from.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl FromStr for PosixTz {
+    type Err = Error;
+
+    /// Expects a POSIX TZ string such as `CET-1CEST,M3.5.0,M10.5.0/3`, reporting the
+    /// position of any mismatch through `Error::ParseError`.
+    fn from_str(s: &str) -> Result<PosixTz, Self::Err> {
+        Parser {
+            input: s,
+            position: 0,
+        }
+        .parse()
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(mut self) -> Result<PosixTz, Error> {
+        let standard_name = self.name()?;
+        let standard_offset = self.offset()?;
+        let standard = LocalTimeType::new(standard_offset, false, standard_name);
+        if self.at_end() {
+            return Ok(PosixTz {
+                text: self.input.to_string(),
+                standard,
+                daylight_saving: None,
+            });
+        }
+
+        let dst_name = self.name()?;
+        let dst_offset = match self.peek() {
+            Some(c) if c.is_ascii_digit() || c == '+' || c == '-' => self.offset()?,
+            _ => UtcOffset::from_seconds(standard_offset.to_seconds() + 3600)
+                .map_err(|_| self.error("a daylight saving offset of at most 18 hours"))?,
+        };
+        let (start, end) = if self.at_end() {
+            (DEFAULT_RULES[0], DEFAULT_RULES[1])
+        } else {
+            self.expect(',', "a comma followed by the start of daylight saving time")?;
+            let start = self.rule()?;
+            self.expect(',', "a comma followed by the end of daylight saving time")?;
+            (start, self.rule()?)
+        };
+        if !self.at_end() {
+            return Err(self.error("the end of the input"));
+        }
+        Ok(PosixTz {
+            text: self.input.to_string(),
+            standard,
+            daylight_saving: Some(DaylightSaving {
+                local_time_type: LocalTimeType::new(dst_offset, true, dst_name),
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Reads an abbreviation of at least three letters, or of letters, digits, and
+    /// signs in angle brackets.
+    fn name(&mut self) -> Result<String, Error> {
+        let rest = &self.input[self.position..];
+        let (name, length) = if let Some(quoted) = rest.strip_prefix('<') {
+            let end = quoted
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '+' || c == '-'))
+                .unwrap_or(quoted.len());
+            if !quoted[end..].starts_with('>') {
+                return Err(self.error_at(
+                    self.position + 1 + end,
+                    "letters, digits, or signs, ending with >",
+                ));
+            }
+            (&quoted[..end], end + 2)
+        } else {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            (&rest[..end], end)
+        };
+        if name.len() < 3 {
+            return Err(self.error(
+                "an abbreviation of at least three letters, or one in angle brackets such as <+03>",
+            ));
+        }
+        self.position += length;
+        Ok(name.to_string())
+    }
+
+    /// Reads an offset in hours west of UTC, giving it as an offset east of UTC.
+    fn offset(&mut self) -> Result<UtcOffset, Error> {
+        let start = self.position;
+        let seconds = self.signed_time(24, "an offset from UTC in hours, such as 5 or -1")?;
+        UtcOffset::from_seconds(-seconds)
+            .map_err(|_| self.error_at(start, "an offset of at most 18 hours"))
+    }
+
+    /// Reads the date and optional time of a transition.
+    fn rule(&mut self) -> Result<TransitionRule, Error> {
+        let date = match self.peek() {
+            Some('J') => {
+                self.position += 1;
+                RuleDate::Julian(self.number(1, 365, "a day of the year between 1 and 365")?)
+            }
+            Some('M') => {
+                self.position += 1;
+                let month = self.number(1, 12, "a month between 1 and 12")? as u8;
+                self.expect('.', "a full stop followed by the week of the month")?;
+                let week = self.number(1, 5, "a week of the month between 1 and 5")? as u8;
+                self.expect('.', "a full stop followed by the day of the week")?;
+                let weekday = self.number(0, 6, "a day of the week between 0 and 6")? as u8;
+                RuleDate::MonthWeekDay {
+                    month,
+                    week,
+                    weekday,
+                }
+            }
+            _ => RuleDate::Ordinal(self.number(0, 365, "a date such as M3.5.0, J60, or 59")?),
+        };
+        let time = if self.peek() == Some('/') {
+            self.position += 1;
+            self.signed_time(167, "a time of day such as 2 or 2:30")?
+        } else {
+            7200
+        };
+        Ok(TransitionRule { date, time })
+    }
+
+    /// Reads `[+|-]hh[:mm[:ss]]` as a number of seconds, with hours up to `max_hours`.
+    fn signed_time(&mut self, max_hours: u16, expected: &str) -> Result<i32, Error> {
+        let sign = match self.peek() {
+            Some('-') => {
+                self.position += 1;
+                -1
+            }
+            Some('+') => {
+                self.position += 1;
+                1
+            }
+            _ => 1,
+        };
+        let mut seconds = i32::from(self.number(0, max_hours, expected)?) * 3600;
+        if self.peek() == Some(':') {
+            self.position += 1;
+            seconds += i32::from(self.number(0, 59, "minutes between 0 and 59")?) * 60;
+            if self.peek() == Some(':') {
+                self.position += 1;
+                seconds += i32::from(self.number(0, 59, "seconds between 0 and 59")?);
+            }
+        }
+        Ok(sign * seconds)
+    }
+
+    fn number(&mut self, min: u16, max: u16, expected: &str) -> Result<u16, Error> {
+        let rest = &self.input[self.position..];
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        match u16::from_str(&rest[..end]) {
+            Ok(n) if n >= min && n <= max => {
+                self.position += end;
+                Ok(n)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    fn expect(&mut self, c: char, expected: &str) -> Result<(), Error> {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn at_end(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    fn error(&self, expected: &str) -> Error {
+        self.error_at(self.position, expected)
+    }
+
+    fn error_at(&self, position: usize, expected: &str) -> Error {
+        Error::ParseError {
+            input: self.input.to_string(),
+            position,
+            expected: expected.to_string(),
+        }
+    }
+}
+
+/// Gets the year containing an instant given in Unix seconds.
+fn year_of(instant: i64) -> i64 {
+    let days = instant.div_euclid(SECONDS_IN_A_DAY);
+    let mut year = 1970 + days * 400 / 146_097;
+    while days_from_civil(year, 1, 1) > days {
+        year -= 1;
+    }
+    while days_from_civil(year + 1, 1, 1) <= days {
+        year += 1;
+    }
+    year
+}
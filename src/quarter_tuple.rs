@@ -0,0 +1,281 @@
+use clock::Clock;
+use date_tuple::DateTuple;
+use error::{Error, TypeName};
+use month_tuple::MonthTuple;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::convert::From;
+use std::fmt;
+use std::str::FromStr;
+
+/// The quarter index of 9999-Q4 as produced by `QuarterTuple::to_quarter_index()`.
+const MAX_QUARTER_INDEX: u32 = 9999 * 4 + 3;
+
+pub type Quarter = QuarterTuple;
+
+/// A container for a quarter of a specific year.
+///
+/// Quarters are one-based: Q1 covers January to March, and Q4 covers October to December.
+///
+/// Only handles values between 0000-Q1 and 9999-Q4 (inclusive).
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct QuarterTuple {
+    y: u16,
+    q: u8,
+}
+
+impl QuarterTuple {
+    /// Produces a new QuarterTuple.
+    ///
+    /// Only accepts a valid quarter value (`1 <= q <= 4`).
+    ///
+    /// Only accepts a valid year value (`0 <= y <= 9999`).
+    pub fn new(y: u16, q: u8) -> Result<QuarterTuple, Error> {
+        if (1..=4).contains(&q) {
+            if y <= 9999 {
+                Ok(QuarterTuple { y, q })
+            } else {
+                Err(Error::InvalidYear {
+                    type_name: TypeName::QuarterTuple,
+                    value: format!("{:?}", QuarterTuple { y, q }),
+                })
+            }
+        } else {
+            Err(Error::InvalidQuarter {
+                type_name: TypeName::QuarterTuple,
+                value: format!("{:?}", QuarterTuple { y, q }),
+            })
+        }
+    }
+
+    /// Returns a `QuarterTuple` of the current quarter according to the system clock.
+    pub fn this_quarter() -> QuarterTuple {
+        QuarterTuple::from(DateTuple::today())
+    }
+
+    /// Returns a `QuarterTuple` of the current quarter in UTC according to a clock.
+    pub fn this_quarter_with_clock<C: Clock + ?Sized>(clock: &C) -> QuarterTuple {
+        QuarterTuple::from(clock.now().get_date())
+    }
+
+    pub fn get_year(self) -> u16 {
+        self.y
+    }
+
+    /// Retrieves the quarter component of the tuple, between 1 and 4.
+    pub fn get_quarter(self) -> u8 {
+        self.q
+    }
+
+    /// Gets a QuarterTuple representing the quarter immediately following
+    /// the current one. Will not go past 9999-Q4.
+    pub fn next_quarter(self) -> QuarterTuple {
+        if self.y == 9999 && self.q == 4 {
+            return self;
+        }
+        if self.q == 4 {
+            QuarterTuple {
+                y: self.y + 1,
+                q: 1,
+            }
+        } else {
+            QuarterTuple {
+                y: self.y,
+                q: self.q + 1,
+            }
+        }
+    }
+
+    /// Gets a QuarterTuple representing the quarter immediately preceding
+    /// the current one. Will not go past 0000-Q1.
+    pub fn previous_quarter(self) -> QuarterTuple {
+        if self.y == 0 && self.q == 1 {
+            return self;
+        }
+        if self.q == 1 {
+            QuarterTuple {
+                y: self.y - 1,
+                q: 4,
+            }
+        } else {
+            QuarterTuple {
+                y: self.y,
+                q: self.q - 1,
+            }
+        }
+    }
+
+    /// Adds a number of quarters to a QuarterTuple.
+    ///
+    /// Will not go past 9999-Q4.
+    pub fn add_quarters(&mut self, quarters: u32) {
+        let new_index = self
+            .to_quarter_index()
+            .saturating_add(quarters)
+            .min(MAX_QUARTER_INDEX);
+        *self = QuarterTuple::from_quarter_index(new_index);
+    }
+
+    /// Subtracts a number of quarters from a QuarterTuple.
+    ///
+    /// Will not go past 0000-Q1.
+    pub fn subtract_quarters(&mut self, quarters: u32) {
+        let new_index = self.to_quarter_index().saturating_sub(quarters);
+        *self = QuarterTuple::from_quarter_index(new_index);
+    }
+
+    /// Adds a number of years to a QuarterTuple.
+    pub fn add_years(&mut self, years: u16) {
+        self.y = self.y.saturating_add(years).min(9999);
+    }
+
+    /// Subtracts a number of years from a QuarterTuple.
+    pub fn subtract_years(&mut self, years: u16) {
+        self.y = self.y.saturating_sub(years);
+    }
+
+    /// Gets the three months which make up the quarter, in order.
+    pub fn get_months(self) -> [MonthTuple; 3] {
+        let first_month = 3 * (self.q - 1) + 1;
+        [
+            MonthTuple::new(self.y, first_month).unwrap(),
+            MonthTuple::new(self.y, first_month + 1).unwrap(),
+            MonthTuple::new(self.y, first_month + 2).unwrap(),
+        ]
+    }
+
+    /// Gets the first date in the quarter.
+    pub fn get_first_date(self) -> DateTuple {
+        DateTuple::new(self.y, 3 * (self.q - 1) + 1, 1).unwrap()
+    }
+
+    /// Gets the last date in the quarter.
+    pub fn get_last_date(self) -> DateTuple {
+        match self.q {
+            1 => DateTuple::new(self.y, 3, 31).unwrap(),
+            2 => DateTuple::new(self.y, 6, 30).unwrap(),
+            3 => DateTuple::new(self.y, 9, 30).unwrap(),
+            _ => DateTuple::new(self.y, 12, 31).unwrap(),
+        }
+    }
+
+    /// Returns the quarter formatted to be human-readable.
+    ///
+    /// ## Examples
+    /// * Q1 2018
+    /// * Q4 1994
+    pub fn to_readable_string(self) -> String {
+        format!("Q{} {:04}", self.q, self.y)
+    }
+
+    /// Gets the number of quarters between 0000-Q1 and this quarter,
+    /// with 0000-Q1 being zero.
+    fn to_quarter_index(self) -> u32 {
+        u32::from(self.y) * 4 + u32::from(self.q) - 1
+    }
+
+    /// The inverse of `to_quarter_index()`. The index must not exceed `MAX_QUARTER_INDEX`.
+    fn from_quarter_index(index: u32) -> QuarterTuple {
+        QuarterTuple {
+            y: (index / 4) as u16,
+            q: (index % 4) as u8 + 1,
+        }
+    }
+}
+
+impl fmt::Display for QuarterTuple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-Q{}", self.y, self.q)
+    }
+}
+
+impl FromStr for QuarterTuple {
+    type Err = Error;
+
+    /// Expects a string formatted like 2018-Q3.
+    ///
+    /// Also accepts 2018Q3.
+    fn from_str(s: &str) -> Result<QuarterTuple, Self::Err> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex = Regex::new(r"^(\d{4})-?Q(\d)$").unwrap();
+        }
+
+        match VALID_FORMAT.captures(s) {
+            Some(caps) => QuarterTuple::new(
+                u16::from_str(&caps[1]).unwrap(),
+                u8::from_str(&caps[2]).unwrap(),
+            )
+            .map_err(|e| Error::InvalidParsedValue {
+                type_name: TypeName::QuarterTuple,
+                error: Box::new(e),
+            }),
+            None => Err(Error::InvalidFormat {
+                type_name: TypeName::QuarterTuple,
+                input: s.to_string(),
+                expected: "2018-Q3",
+            }),
+        }
+    }
+}
+
+impl PartialOrd for QuarterTuple {
+    fn partial_cmp(&self, other: &QuarterTuple) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for QuarterTuple {
+    fn cmp(&self, other: &QuarterTuple) -> Ordering {
+        if self.y == other.y {
+            self.q.cmp(&other.q)
+        } else {
+            self.y.cmp(&other.y)
+        }
+    }
+}
+
+impl From<MonthTuple> for QuarterTuple {
+    fn from(month: MonthTuple) -> Self {
+        QuarterTuple {
+            y: month.get_year(),
+            q: (month.get_month() - 1) / 3 + 1,
+        }
+    }
+}
+
+impl From<DateTuple> for QuarterTuple {
+    fn from(date: DateTuple) -> Self {
+        QuarterTuple::from(MonthTuple::from(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::QuarterTuple;
+
+    #[test]
+    fn test_next_quarter() {
+        let tuple1 = QuarterTuple::new(2000, 2).unwrap();
+        let tuple2 = QuarterTuple::new(2000, 4).unwrap();
+        let tuple3 = QuarterTuple::new(9999, 4).unwrap();
+        assert_eq!(QuarterTuple { y: 2000, q: 3 }, tuple1.next_quarter());
+        assert_eq!(QuarterTuple { y: 2001, q: 1 }, tuple2.next_quarter());
+        assert_eq!(tuple3, tuple3.next_quarter());
+    }
+
+    #[test]
+    fn test_previous_quarter() {
+        let tuple1 = QuarterTuple::new(2000, 2).unwrap();
+        let tuple2 = QuarterTuple::new(2000, 1).unwrap();
+        let tuple3 = QuarterTuple::new(0, 1).unwrap();
+        assert_eq!(QuarterTuple { y: 2000, q: 1 }, tuple1.previous_quarter());
+        assert_eq!(QuarterTuple { y: 1999, q: 4 }, tuple2.previous_quarter());
+        assert_eq!(tuple3, tuple3.previous_quarter());
+    }
+}
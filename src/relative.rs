@@ -0,0 +1,215 @@
+//! Describing dates and times relative to one another, such as "3 hours ago" or "in 2 days".
+//!
+//! A difference is described in the smallest unit for which it is below that unit's
+//! threshold, so with the default thresholds 90 minutes is "1 hour ago" rather than
+//! "90 minutes ago". Differences are counted in whole units, with months counted as
+//! 30 days and years as 365 days.
+//!
+//! Words such as "yesterday", "last week", or "next month" are only used when the two
+//! values fall on adjacent calendar days, weeks (starting on Monday), months, or years,
+//! so ten days before a Tuesday is "1 week ago" rather than "last week".
+
+use date_time_tuple::DateTimeTuple;
+use date_tuple::DateTuple;
+use std::cmp::{max, min};
+
+/// A unit in which a relative time can be described.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+const UNITS: [Unit; 7] = [
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
+impl Unit {
+    fn seconds(self) -> u64 {
+        match self {
+            Unit::Second => 1,
+            Unit::Minute => 60,
+            Unit::Hour => 3600,
+            Unit::Day => 86_400,
+            Unit::Week => 604_800,
+            Unit::Month => 2_592_000,
+            Unit::Year => 31_536_000,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Unit::Second => "second",
+            Unit::Minute => "minute",
+            Unit::Hour => "hour",
+            Unit::Day => "day",
+            Unit::Week => "week",
+            Unit::Month => "month",
+            Unit::Year => "year",
+        }
+    }
+
+    /// Numbers the calendar day, week, month, or year containing `date`, so that
+    /// consecutive periods have consecutive numbers.
+    fn calendar_index(self, date: DateTuple) -> u32 {
+        match self {
+            // Day 1 was a Saturday, so weeks starting on Monday begin at multiples of 7.
+            Unit::Week => (date.to_days() + 4) / 7,
+            Unit::Month => u32::from(date.get_year()) * 12 + u32::from(date.get_month()),
+            Unit::Year => u32::from(date.get_year()),
+            _ => date.to_days(),
+        }
+    }
+}
+
+/// Produces relative descriptions of dates and times, with configurable thresholds
+/// and granularity.
+///
+/// `RelativeFormatter::new()` gives the defaults used by `humanize_relative_to()`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct RelativeFormatter {
+    just_now: u32,
+    thresholds: [u32; 6],
+    granularity: Unit,
+}
+
+impl RelativeFormatter {
+    /// Produces a RelativeFormatter with the default settings: differences under
+    /// 10 seconds are "just now", and each unit is used until it reaches the size
+    /// of the next (60 seconds, 60 minutes, 24 hours, 7 days, 4 weeks, and 12 months).
+    pub fn new() -> RelativeFormatter {
+        RelativeFormatter {
+            just_now: 10,
+            thresholds: [60, 60, 24, 7, 4, 12],
+            granularity: Unit::Second,
+        }
+    }
+
+    /// Sets the number of seconds under which a difference between two date-times
+    /// is described as "just now". Zero turns this off.
+    pub fn with_just_now(mut self, seconds: u32) -> RelativeFormatter {
+        self.just_now = seconds;
+        self
+    }
+
+    /// Sets the number of `unit`s at which the next larger unit is used instead,
+    /// so a threshold of 45 for `Unit::Minute` describes 50 minutes as "1 hour ago".
+    ///
+    /// Years are never replaced by a larger unit, so their threshold is ignored.
+    pub fn with_threshold(mut self, unit: Unit, threshold: u32) -> RelativeFormatter {
+        if unit != Unit::Year {
+            self.thresholds[unit as usize] = threshold;
+        }
+        self
+    }
+
+    /// Sets the smallest unit to describe differences in.
+    ///
+    /// Differences smaller than one of this unit are described as "just now" for
+    /// seconds, minutes, and hours. For the larger units they are described as
+    /// "today", "this week", "this month", or "this year" within the same calendar
+    /// period, and otherwise as "yesterday", "next week", and so on.
+    pub fn with_granularity(mut self, unit: Unit) -> RelativeFormatter {
+        self.granularity = unit;
+        self
+    }
+
+    /// Describes `time` relative to `reference`, such as "3 hours ago" if `time` is
+    /// three hours before `reference`.
+    pub fn humanize_date_time(self, time: DateTimeTuple, reference: DateTimeTuple) -> String {
+        let difference = time.signed_duration_since(reference);
+        let seconds = difference.unsigned_abs().to_seconds();
+        if seconds < u64::from(self.just_now) {
+            return String::from("just now");
+        }
+        self.describe(
+            seconds,
+            !difference.is_negative(),
+            self.granularity,
+            time.get_date(),
+            reference.get_date(),
+        )
+    }
+
+    /// Describes `date` relative to `reference`, such as "yesterday" if `date` is
+    /// the day before `reference`.
+    ///
+    /// A granularity smaller than `Unit::Day` is treated as `Unit::Day`.
+    pub fn humanize_date(self, date: DateTuple, reference: DateTuple) -> String {
+        let (days, future) = if date < reference {
+            (reference.to_days() - date.to_days(), false)
+        } else {
+            (date.to_days() - reference.to_days(), true)
+        };
+        self.describe(
+            u64::from(days) * Unit::Day.seconds(),
+            future,
+            max(self.granularity, Unit::Day),
+            date,
+            reference,
+        )
+    }
+
+    fn describe(
+        self,
+        seconds: u64,
+        future: bool,
+        granularity: Unit,
+        date: DateTuple,
+        reference: DateTuple,
+    ) -> String {
+        let mut unit = granularity;
+        while unit != Unit::Year
+            && seconds / unit.seconds() >= u64::from(self.thresholds[unit as usize])
+        {
+            unit = UNITS[unit as usize + 1];
+        }
+        let mut count = seconds / unit.seconds();
+        if count == 0 && unit != granularity {
+            // A threshold lower than the size of the next unit has been passed.
+            count = 1;
+        }
+        if unit < Unit::Day {
+            return match (count, future) {
+                (0, _) => String::from("just now"),
+                (1, false) => format!("1 {} ago", unit.name()),
+                (1, true) => format!("in 1 {}", unit.name()),
+                (_, false) => format!("{} {}s ago", count, unit.name()),
+                (_, true) => format!("in {} {}s", count, unit.name()),
+            };
+        }
+        let date_index = unit.calendar_index(date);
+        let reference_index = unit.calendar_index(reference);
+        let periods = max(date_index, reference_index) - min(date_index, reference_index);
+        // Less than one unit apart can be at most one calendar period apart.
+        match (count, periods, unit, future) {
+            (0, 0, Unit::Day, _) => String::from("today"),
+            (0, 0, _, _) => format!("this {}", unit.name()),
+            (0, _, Unit::Day, false) | (1, 1, Unit::Day, false) => String::from("yesterday"),
+            (0, _, Unit::Day, true) | (1, 1, Unit::Day, true) => String::from("tomorrow"),
+            (0, _, _, false) | (1, 1, _, false) => format!("last {}", unit.name()),
+            (0, _, _, true) | (1, 1, _, true) => format!("next {}", unit.name()),
+            (1, _, _, false) => format!("1 {} ago", unit.name()),
+            (1, _, _, true) => format!("in 1 {}", unit.name()),
+            (_, _, _, false) => format!("{} {}s ago", count, unit.name()),
+            (_, _, _, true) => format!("in {} {}s", count, unit.name()),
+        }
+    }
+}
+
+impl Default for RelativeFormatter {
+    fn default() -> RelativeFormatter {
+        RelativeFormatter::new()
+    }
+}
@@ -1,12 +1,19 @@
 use crate::date_time_tuple::DateTimeTuple;
+use clock::Clock;
 use date_utils;
+use error::{Error, TypeName};
+use format::{Pattern, Value};
+use parse::{self, Kind};
 use regex::Regex;
 use std::cmp::Ordering;
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::fmt;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 use std::str::FromStr;
 
+/// The total number of seconds in the longest possible `Duration`.
+const MAX_DURATION_SECONDS: u64 = 3600 * u32::MAX as u64 + 3599;
+
 pub type Time = TimeTuple;
 pub type TimeOfDay = TimeTuple;
 
@@ -63,11 +70,16 @@ impl TimeTuple {
         }
     }
 
-    /// Returns a `TimeTuple` of the current time as `std::time::SystemTime` provides it.
+    /// Returns a `TimeTuple` of the current time in UTC as `std::time::SystemTime` provides it.
     pub fn now() -> TimeTuple {
         date_utils::now_as_timetuple()
     }
 
+    /// Returns a `TimeTuple` of the current time in UTC according to a clock.
+    pub fn now_with_clock<C: Clock + ?Sized>(clock: &C) -> TimeTuple {
+        clock.now().get_time()
+    }
+
     pub fn get_hours(self) -> u8 {
         self.h
     }
@@ -87,6 +99,32 @@ impl TimeTuple {
         format!("{:02}:{:02}", self.h, self.m)
     }
 
+    /// Formats the time using a strftime-like pattern; `"%-I:%M %p"` produces `8:30 PM`.
+    ///
+    /// See the `format` module for the available directives.
+    pub fn format(self, pattern: &str) -> Result<String, Error> {
+        self.format_with(&Pattern::new(pattern)?)
+    }
+
+    /// Formats the time using a pattern compiled in advance with `Pattern::new()`.
+    pub fn format_with(self, pattern: &Pattern) -> Result<String, Error> {
+        pattern.format_value(Value::Time(self))
+    }
+
+    /// Parses a time from a string using a strftime-like pattern, such as `"%-I:%M %p"`
+    /// for `8:30 PM`.
+    ///
+    /// See the `format` module for the available directives. If the string doesn't match
+    /// the pattern, `Error::ParseError` gives the position at which it stopped matching.
+    pub fn parse_from_str(input: &str, pattern: &str) -> Result<TimeTuple, Error> {
+        TimeTuple::parse_with(input, &Pattern::new(pattern)?)
+    }
+
+    /// Parses a time using a pattern compiled in advance with `Pattern::new()`.
+    pub fn parse_with(input: &str, pattern: &Pattern) -> Result<TimeTuple, Error> {
+        parse::parse(input, pattern, Kind::Time)?.to_time()
+    }
+
     /// Gets the total number of seconds in the tuple.
     pub fn to_seconds(self) -> u32 {
         3600 * u32::from(self.h) + 60 * u32::from(self.m) + u32::from(self.s)
@@ -147,7 +185,7 @@ impl fmt::Display for TimeTuple {
 }
 
 impl FromStr for TimeTuple {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<TimeTuple, Self::Err> {
         lazy_static! {
@@ -155,10 +193,11 @@ impl FromStr for TimeTuple {
         }
 
         if !VALID_FORMAT.is_match(s) {
-            Err(format!(
-                "Invalid str formatting of TimeTuple: {}\nExpects a string formatted like 08:30:05",
-                s
-            ))
+            Err(Error::InvalidFormat {
+                type_name: TypeName::TimeTuple,
+                input: s.to_string(),
+                expected: "08:30:05",
+            })
         } else {
             let mut parts = s.split(':');
             Ok(TimeTuple::new(
@@ -172,7 +211,7 @@ impl FromStr for TimeTuple {
 
 impl PartialOrd for TimeTuple {
     fn partial_cmp(&self, other: &TimeTuple) -> Option<Ordering> {
-        self.to_seconds().partial_cmp(&other.to_seconds())
+        Some(self.cmp(other))
     }
 }
 
@@ -253,7 +292,10 @@ impl Duration {
     /// Same as `Duration::new()` but takes the total number of seconds
     /// as its argument and calculates the hours, minutes, and seconds
     /// from that.
-    pub fn from_seconds(mut total_seconds: u64) -> Duration {
+    ///
+    /// Will not go past `u32::MAX` hours, 59 minutes, and 59 seconds.
+    pub fn from_seconds(total_seconds: u64) -> Duration {
+        let mut total_seconds = total_seconds.min(MAX_DURATION_SECONDS);
         let h = total_seconds / 3600;
         total_seconds -= h * 3600;
         let m = total_seconds / 60;
@@ -267,18 +309,11 @@ impl Duration {
 
     /// Calculates the `Duration` between two `DateTimeTuple`s.
     pub fn between(dt1: DateTimeTuple, dt2: DateTimeTuple) -> Duration {
-        if dt1 == dt2 {
-            return Duration { h: 0, m: 0, s: 0 };
-        }
-        let smaller = if dt1 < dt2 { dt1 } else { dt2 };
-        let greater = if dt1 < dt2 { dt2 } else { dt1 };
-        let days_between = greater.get_date().to_days() - smaller.get_date().to_days();
-        if days_between == 0 {
-            Duration::from(greater.get_time()) - Duration::from(smaller.get_time())
+        let (seconds1, seconds2) = (dt1.to_seconds(), dt2.to_seconds());
+        if seconds1 > seconds2 {
+            Duration::from_seconds(seconds1 - seconds2)
         } else {
-            let time_between = Duration::from(greater.get_time()) + Duration::new(24, 0, 0)
-                - Duration::from(smaller.get_time());
-            time_between + Duration::new(24 * (days_between - 1), 0, 0)
+            Duration::from_seconds(seconds2 - seconds1)
         }
     }
 
@@ -313,6 +348,32 @@ impl Duration {
         format!("{}:{:02}", self.h, self.m)
     }
 
+    /// Formats the Duration using a strftime-like pattern; `"%-Hh %Mm"` produces `150h 05m`.
+    ///
+    /// See the `format` module for the available directives.
+    pub fn format(self, pattern: &str) -> Result<String, Error> {
+        self.format_with(&Pattern::new(pattern)?)
+    }
+
+    /// Formats the Duration using a pattern compiled in advance with `Pattern::new()`.
+    pub fn format_with(self, pattern: &Pattern) -> Result<String, Error> {
+        pattern.format_value(Value::Duration(self))
+    }
+
+    /// Parses a Duration from a string using a strftime-like pattern, such as `"%Hh %Mm"`
+    /// for `150h 05m`.
+    ///
+    /// See the `format` module for the available directives. If the string doesn't match
+    /// the pattern, `Error::ParseError` gives the position at which it stopped matching.
+    pub fn parse_from_str(input: &str, pattern: &str) -> Result<Duration, Error> {
+        Duration::parse_with(input, &Pattern::new(pattern)?)
+    }
+
+    /// Parses a Duration using a pattern compiled in advance with `Pattern::new()`.
+    pub fn parse_with(input: &str, pattern: &Pattern) -> Result<Duration, Error> {
+        parse::parse(input, pattern, Kind::Duration)?.to_duration()
+    }
+
     /// Gets the total number of seconds in the Duration.
     pub fn to_seconds(self) -> u64 {
         3600 * u64::from(self.h) + 60 * u64::from(self.m) + u64::from(self.s)
@@ -323,6 +384,173 @@ impl Duration {
         60 * self.h + u32::from(self.m)
     }
 
+    /// Produces an ISO 8601 duration such as PT1H30M for 1 hour and 30 minutes.
+    ///
+    /// Components which are zero are left out, and days are never used, so 26 hours
+    /// produces PT26H. A zero Duration produces PT0S.
+    pub fn to_iso8601(self) -> String {
+        if self.to_seconds() == 0 {
+            return String::from("PT0S");
+        }
+        let mut result = String::from("PT");
+        if self.h > 0 {
+            result.push_str(&format!("{}H", self.h));
+        }
+        if self.m > 0 {
+            result.push_str(&format!("{}M", self.m));
+        }
+        if self.s > 0 {
+            result.push_str(&format!("{}S", self.s));
+        }
+        result
+    }
+
+    /// Parses an ISO 8601 duration such as PT1H30M, P2DT3H, or PT90S.
+    ///
+    /// Weeks and days are taken to be exactly 7 and 24 hours long, and fractional seconds
+    /// are truncated. Years and months have no fixed length, so a duration containing
+    /// them fails with `Error::AmbiguousDuration` unless they are zero.
+    pub fn from_iso8601(s: &str) -> Result<Duration, Error> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex = Regex::new(
+                r"^P(?:(\d+)Y)?(?:(\d+)M)?(?:(\d+)W)?(?:(\d+)D)?(?:T(?:(\d+)H)?(?:(\d+)M)?(?:(\d+)(?:[.,]\d+)?S)?)?$"
+            )
+            .unwrap();
+        }
+
+        let caps = match VALID_FORMAT.captures(s) {
+            Some(caps) if s != "P" && !s.ends_with('T') => caps,
+            _ => {
+                return Err(Error::InvalidFormat {
+                    type_name: TypeName::Duration,
+                    input: s.to_string(),
+                    expected: "PT1H30M",
+                })
+            }
+        };
+        let out_of_range = || Error::OutOfRange {
+            type_name: TypeName::Duration,
+            value: s.to_string(),
+            min: String::from("PT0S"),
+            max: Duration::from_seconds(MAX_DURATION_SECONDS).to_iso8601(),
+        };
+        let mut components = [0; 7];
+        for (i, component) in components.iter_mut().enumerate() {
+            if let Some(m) = caps.get(i + 1) {
+                *component = u64::from_str(m.as_str()).map_err(|_| out_of_range())?;
+            }
+        }
+        if components[0] > 0 || components[1] > 0 {
+            return Err(Error::AmbiguousDuration {
+                input: s.to_string(),
+            });
+        }
+        let total_seconds = [604_800, 86_400, 3600, 60, 1]
+            .iter()
+            .zip(components[2..].iter())
+            .try_fold(0u64, |total, (unit, n)| {
+                n.checked_mul(*unit).and_then(|n| total.checked_add(n))
+            })
+            .filter(|total| *total <= MAX_DURATION_SECONDS)
+            .ok_or_else(out_of_range)?;
+        Ok(Duration::from_seconds(total_seconds))
+    }
+
+    /// Produces the shortest human-readable form of the Duration, counting 24 hours as a day.
+    ///
+    /// ## Examples
+    /// * 2d 3h
+    /// * 45m
+    /// * 1h 30m 15s
+    /// * 0s
+    pub fn to_human_string(self) -> String {
+        let parts: Vec<String> = [
+            (self.h / 24, "d"),
+            (self.h % 24, "h"),
+            (u32::from(self.m), "m"),
+            (u32::from(self.s), "s"),
+        ]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{}{}", n, unit))
+        .collect();
+        if parts.is_empty() {
+            String::from("0s")
+        } else {
+            parts.join(" ")
+        }
+    }
+
+    /// Parses a human-friendly duration such as 1h30m15s, 45 min, or 1 hour 30 minutes.
+    ///
+    /// Each number must be followed by a unit: weeks (`w`, `wk`, `week`), days (`d`, `day`),
+    /// hours (`h`, `hr`, `hour`), minutes (`m`, `min`, `minute`), or seconds (`s`, `sec`,
+    /// `second`), each of which may also be plural. Units ignore case, may be separated
+    /// from their number by spaces, and may appear in any order. Components may be
+    /// separated by spaces or commas.
+    ///
+    /// Fails with `Error::ParseError` giving the position of the first part which
+    /// couldn't be read.
+    pub fn parse_human(s: &str) -> Result<Duration, Error> {
+        let error = |position: usize, expected: &str| Error::ParseError {
+            input: s.to_string(),
+            position,
+            expected: expected.to_string(),
+        };
+        let out_of_range = || Error::OutOfRange {
+            type_name: TypeName::Duration,
+            value: s.to_string(),
+            min: String::from("0s"),
+            max: Duration::from_seconds(MAX_DURATION_SECONDS).to_human_string(),
+        };
+        // Gives the position after any characters from `position` onwards matching `skipped`.
+        let skip = |position: usize, skipped: &dyn Fn(char) -> bool| {
+            position
+                + s[position..]
+                    .chars()
+                    .take_while(|c| skipped(*c))
+                    .map(char::len_utf8)
+                    .sum::<usize>()
+        };
+        let is_separator = |c: char| c.is_whitespace() || c == ',';
+
+        let mut total_seconds: u64 = 0;
+        let mut position = skip(0, &is_separator);
+        if position == s.len() {
+            return Err(error(position, "a number"));
+        }
+        while position < s.len() {
+            let digits = s[position..].bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(error(position, "a number"));
+            }
+            let n = u64::from_str(&s[position..position + digits]).map_err(|_| out_of_range())?;
+            position = skip(position + digits, &|c| c == ' ');
+            let letters = s[position..]
+                .bytes()
+                .take_while(u8::is_ascii_alphabetic)
+                .count();
+            let unit = match s[position..position + letters]
+                .to_ascii_lowercase()
+                .as_str()
+            {
+                "w" | "wk" | "wks" | "week" | "weeks" => 604_800,
+                "d" | "day" | "days" => 86_400,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+                "m" | "min" | "mins" | "minute" | "minutes" => 60,
+                "s" | "sec" | "secs" | "second" | "seconds" => 1,
+                _ => return Err(error(position, "a unit such as h, min, or seconds")),
+            };
+            total_seconds = n
+                .checked_mul(unit)
+                .and_then(|n| total_seconds.checked_add(n))
+                .filter(|total| *total <= MAX_DURATION_SECONDS)
+                .ok_or_else(out_of_range)?;
+            position = skip(position + letters, &is_separator);
+        }
+        Ok(Duration::from_seconds(total_seconds))
+    }
+
     /// Adds a number of seconds to the Duration,
     /// wrapping the same way `Duration::new()` does.
     pub fn add_seconds(&mut self, seconds: u32) {
@@ -332,6 +560,9 @@ impl Duration {
 
     /// Subtracts a number of seconds from the Duration,
     /// wrapping the same way `Duration::new()` does.
+    ///
+    /// Panics if the result would be negative; see `checked_sub_seconds()` and
+    /// `saturating_sub_seconds()` for alternatives.
     pub fn subtract_seconds(&mut self, seconds: u32) {
         *self = Duration::from_seconds(self.to_seconds() - u64::from(seconds));
     }
@@ -345,6 +576,9 @@ impl Duration {
 
     /// Subtracts a number of minutes from the Duration,
     /// wrapping the same way `Duration::new()` does.
+    ///
+    /// Panics if the result would be negative; see `checked_sub_minutes()` and
+    /// `saturating_sub_minutes()` for alternatives.
     pub fn subtract_minutes(&mut self, minutes: u32) {
         *self = Duration::from_seconds(self.to_seconds() - u64::from(minutes) * 60);
     }
@@ -358,10 +592,106 @@ impl Duration {
 
     /// Subtracts a number of hours from the Duration,
     /// wrapping the same way `Duration::new()` does.
+    ///
+    /// Panics if the result would be negative; see `checked_sub_hours()` and
+    /// `saturating_sub_hours()` for alternatives.
     pub fn subtract_hours(&mut self, hours: u32) {
         let new_hours = self.h - hours;
         *self = Duration::new(new_hours, u32::from(self.m), u32::from(self.s));
     }
+
+    /// Adds a number of seconds to the Duration, returning `None` if the
+    /// result would be longer than `u32::MAX` hours.
+    pub fn checked_add_seconds(self, seconds: u32) -> Option<Duration> {
+        self.to_seconds()
+            .checked_add(u64::from(seconds))
+            .filter(|s| *s <= MAX_DURATION_SECONDS)
+            .map(Duration::from_seconds)
+    }
+
+    /// Subtracts a number of seconds from the Duration, returning `None` if the
+    /// result would be negative.
+    pub fn checked_sub_seconds(self, seconds: u32) -> Option<Duration> {
+        self.to_seconds()
+            .checked_sub(u64::from(seconds))
+            .map(Duration::from_seconds)
+    }
+
+    /// Adds a number of minutes to the Duration, returning `None` if the
+    /// result would be longer than `u32::MAX` hours.
+    pub fn checked_add_minutes(self, minutes: u32) -> Option<Duration> {
+        self.to_seconds()
+            .checked_add(60 * u64::from(minutes))
+            .filter(|s| *s <= MAX_DURATION_SECONDS)
+            .map(Duration::from_seconds)
+    }
+
+    /// Subtracts a number of minutes from the Duration, returning `None` if the
+    /// result would be negative.
+    pub fn checked_sub_minutes(self, minutes: u32) -> Option<Duration> {
+        self.to_seconds()
+            .checked_sub(60 * u64::from(minutes))
+            .map(Duration::from_seconds)
+    }
+
+    /// Adds a number of hours to the Duration, returning `None` if the
+    /// result would be longer than `u32::MAX` hours.
+    pub fn checked_add_hours(self, hours: u32) -> Option<Duration> {
+        self.to_seconds()
+            .checked_add(3600 * u64::from(hours))
+            .filter(|s| *s <= MAX_DURATION_SECONDS)
+            .map(Duration::from_seconds)
+    }
+
+    /// Subtracts a number of hours from the Duration, returning `None` if the
+    /// result would be negative.
+    pub fn checked_sub_hours(self, hours: u32) -> Option<Duration> {
+        self.to_seconds()
+            .checked_sub(3600 * u64::from(hours))
+            .map(Duration::from_seconds)
+    }
+
+    /// Adds a number of seconds to the Duration, stopping at the longest possible Duration.
+    pub fn saturating_add_seconds(self, seconds: u32) -> Duration {
+        Duration::from_seconds(
+            self.to_seconds()
+                .saturating_add(u64::from(seconds))
+                .min(MAX_DURATION_SECONDS),
+        )
+    }
+
+    /// Subtracts a number of seconds from the Duration, stopping at zero.
+    pub fn saturating_sub_seconds(self, seconds: u32) -> Duration {
+        Duration::from_seconds(self.to_seconds().saturating_sub(u64::from(seconds)))
+    }
+
+    /// Adds a number of minutes to the Duration, stopping at the longest possible Duration.
+    pub fn saturating_add_minutes(self, minutes: u32) -> Duration {
+        Duration::from_seconds(
+            self.to_seconds()
+                .saturating_add(60 * u64::from(minutes))
+                .min(MAX_DURATION_SECONDS),
+        )
+    }
+
+    /// Subtracts a number of minutes from the Duration, stopping at zero.
+    pub fn saturating_sub_minutes(self, minutes: u32) -> Duration {
+        Duration::from_seconds(self.to_seconds().saturating_sub(60 * u64::from(minutes)))
+    }
+
+    /// Adds a number of hours to the Duration, stopping at the longest possible Duration.
+    pub fn saturating_add_hours(self, hours: u32) -> Duration {
+        Duration::from_seconds(
+            self.to_seconds()
+                .saturating_add(3600 * u64::from(hours))
+                .min(MAX_DURATION_SECONDS),
+        )
+    }
+
+    /// Subtracts a number of hours from the Duration, stopping at zero.
+    pub fn saturating_sub_hours(self, hours: u32) -> Duration {
+        Duration::from_seconds(self.to_seconds().saturating_sub(3600 * u64::from(hours)))
+    }
 }
 
 impl fmt::Display for Duration {
@@ -371,21 +701,27 @@ impl fmt::Display for Duration {
 }
 
 impl FromStr for Duration {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Duration, Self::Err> {
         lazy_static! {
             static ref VALID_FORMAT: Regex = Regex::new(r"^\d+:\d{2}:\d{2}$").unwrap();
         }
         if !VALID_FORMAT.is_match(s) {
-            Err(format!(
-                "Invalid str formatting of Duration: {}\nExpects a string formatted like 8:30:05",
-                s
-            ))
+            Err(Error::InvalidFormat {
+                type_name: TypeName::Duration,
+                input: s.to_string(),
+                expected: "8:30:05",
+            })
         } else {
             let mut parts = s.split(':');
+            let hours = u32::from_str(parts.next().unwrap()).map_err(|_| Error::ParseError {
+                input: s.to_string(),
+                position: 0,
+                expected: format!("an hour between 0 and {}", u32::MAX),
+            })?;
             Ok(Duration::new(
-                u32::from_str(parts.next().unwrap()).unwrap(),
+                hours,
                 u32::from_str(parts.next().unwrap()).unwrap(),
                 u32::from_str(parts.next().unwrap()).unwrap(),
             ))
@@ -393,9 +729,50 @@ impl FromStr for Duration {
     }
 }
 
+/// Serialises a Duration in the same format as `to_string()`, such as "8:30:05".
+///
+/// Use `#[serde(with = "date_time::time_tuple::iso8601_duration")]` on a field to
+/// serialise it as an ISO 8601 duration instead.
+#[cfg(feature = "serde_support")]
+impl serde::Serialize for Duration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde_support")]
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Duration::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serialisation of a Duration as an ISO 8601 duration such as "PT1H30M".
+///
+/// For use with `#[serde(with = "date_time::time_tuple::iso8601_duration")]`.
+#[cfg(feature = "serde_support")]
+pub mod iso8601_duration {
+    use super::Duration;
+
+    pub fn serialize<S: serde::Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&duration.to_iso8601())
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Duration::from_iso8601(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 impl PartialOrd for Duration {
     fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
-        self.to_seconds().partial_cmp(&other.to_seconds())
+        Some(self.cmp(other))
     }
 }
 
@@ -430,21 +807,13 @@ impl AddAssign for Duration {
 impl Sub for Duration {
     type Output = Duration;
     fn sub(self, other: Duration) -> Duration {
-        Duration::new(
-            self.h - other.h,
-            u32::from(self.m - other.m),
-            u32::from(self.s - other.s),
-        )
+        Duration::from_seconds(self.to_seconds() - other.to_seconds())
     }
 }
 
 impl SubAssign for Duration {
     fn sub_assign(&mut self, other: Duration) {
-        *self = Duration::new(
-            self.h - other.h,
-            u32::from(self.m - other.m),
-            u32::from(self.s - other.s),
-        );
+        *self = *self - other;
     }
 }
 
@@ -454,6 +823,197 @@ impl From<TimeTuple> for Duration {
     }
 }
 
+/// A wrapper for a duration which may be negative.
+///
+/// Holds a direction alongside a `Duration`, and is otherwise the same.
+/// A zero duration is never negative.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct SignedDuration {
+    negative: bool,
+    d: Duration,
+}
+
+impl SignedDuration {
+    /// Produces a new SignedDuration from a total number of seconds,
+    /// which is negative if `total_seconds` is.
+    ///
+    /// Will not go past `u32::MAX` hours, 59 minutes, and 59 seconds either way.
+    pub fn from_seconds(total_seconds: i64) -> SignedDuration {
+        SignedDuration {
+            negative: total_seconds < 0,
+            d: Duration::from_seconds(total_seconds.unsigned_abs()),
+        }
+    }
+
+    /// Calculates the `SignedDuration` from `from` to `to`, which is
+    /// negative if `to` is earlier than `from`.
+    pub fn between(from: DateTimeTuple, to: DateTimeTuple) -> SignedDuration {
+        let duration = Duration::between(from, to);
+        if to < from {
+            -SignedDuration::from(duration)
+        } else {
+            SignedDuration::from(duration)
+        }
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.negative
+    }
+
+    pub fn get_hours(self) -> u32 {
+        self.d.h
+    }
+
+    pub fn get_minutes(self) -> u8 {
+        self.d.m
+    }
+
+    pub fn get_seconds(self) -> u8 {
+        self.d.s
+    }
+
+    /// Gets the total number of seconds in the SignedDuration,
+    /// which is negative if the SignedDuration is.
+    pub fn to_seconds(self) -> i64 {
+        let seconds = self.d.to_seconds() as i64;
+        if self.negative {
+            -seconds
+        } else {
+            seconds
+        }
+    }
+
+    /// Gets the SignedDuration with the same length in the positive direction.
+    pub fn abs(self) -> SignedDuration {
+        SignedDuration {
+            negative: false,
+            d: self.d,
+        }
+    }
+
+    /// Gets the length of the SignedDuration as an unsigned `Duration`.
+    pub fn unsigned_abs(self) -> Duration {
+        self.d
+    }
+}
+
+impl fmt::Display for SignedDuration {
+    /// Formats the same as `Duration` with a leading `-` if negative, such as -1:30:00.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-{}", self.d)
+        } else {
+            write!(f, "{}", self.d)
+        }
+    }
+}
+
+impl FromStr for SignedDuration {
+    type Err = Error;
+
+    /// Expects a string formatted like a `Duration`, optionally preceded by
+    /// a `-` or `+` sign, such as -8:30:05.
+    fn from_str(s: &str) -> Result<SignedDuration, Self::Err> {
+        let (negative, unsigned) = if let Some(rest) = s.strip_prefix('-') {
+            (true, rest)
+        } else {
+            (false, s.strip_prefix('+').unwrap_or(s))
+        };
+        match Duration::from_str(unsigned) {
+            Ok(d) if negative => Ok(-SignedDuration::from(d)),
+            Ok(d) => Ok(SignedDuration::from(d)),
+            Err(Error::ParseError {
+                position, expected, ..
+            }) => Err(Error::ParseError {
+                input: s.to_string(),
+                position: position + s.len() - unsigned.len(),
+                expected,
+            }),
+            Err(_) => Err(Error::InvalidFormat {
+                type_name: TypeName::SignedDuration,
+                input: s.to_string(),
+                expected: "-8:30:05",
+            }),
+        }
+    }
+}
+
+impl PartialOrd for SignedDuration {
+    fn partial_cmp(&self, other: &SignedDuration) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for SignedDuration {
+    fn cmp(&self, other: &SignedDuration) -> Ordering {
+        self.to_seconds().cmp(&other.to_seconds())
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+    fn neg(self) -> SignedDuration {
+        SignedDuration {
+            negative: !self.negative && self.d.to_seconds() != 0,
+            d: self.d,
+        }
+    }
+}
+
+impl Add for SignedDuration {
+    type Output = SignedDuration;
+    fn add(self, other: SignedDuration) -> SignedDuration {
+        SignedDuration::from_seconds(self.to_seconds() + other.to_seconds())
+    }
+}
+
+impl AddAssign for SignedDuration {
+    fn add_assign(&mut self, other: SignedDuration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for SignedDuration {
+    type Output = SignedDuration;
+    fn sub(self, other: SignedDuration) -> SignedDuration {
+        SignedDuration::from_seconds(self.to_seconds() - other.to_seconds())
+    }
+}
+
+impl SubAssign for SignedDuration {
+    fn sub_assign(&mut self, other: SignedDuration) {
+        *self = *self - other;
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(duration: Duration) -> Self {
+        SignedDuration {
+            negative: false,
+            d: duration,
+        }
+    }
+}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = Error;
+
+    /// Fails if the `SignedDuration` is negative.
+    fn try_from(duration: SignedDuration) -> Result<Self, Self::Error> {
+        if duration.negative {
+            Err(Error::OutOfRange {
+                type_name: TypeName::Duration,
+                value: duration.to_string(),
+                min: String::from("0:00:00"),
+                max: Duration::new(u32::MAX, 59, 59).to_string(),
+            })
+        } else {
+            Ok(duration.d)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,580 @@
+//! Time zones read from compiled TZif files, as described by RFC 8536.
+//!
+//! Zones are loaded by their IANA names, such as `Europe/Berlin`, from a zoneinfo
+//! directory. This is the directory given to `set_zoneinfo_dir()` if it has been called,
+//! or else the `TZDIR` environment variable, or else `/usr/share/zoneinfo`.
+//!
+//! Files of versions 1 to 3 are read, using the 64-bit data of version 2 and later.
+//! Leap second records are skipped, so zones from the `right/` directory are not
+//! supported. Times before the first transition in a file use its first local time
+//! type. Times after the last transition follow the POSIX TZ string at the end of
+//! the file, as read by `posix_tz::PosixTz`, or else keep the type of that transition.
+//!
+//! A `TimeZone` converts between UTC and local time via `zoned_date_time::ZonedDateTime`.
+//! The system's own time zone is given by `TimeZone::local()`, from the `TZ` environment
+//! variable or `/etc/localtime`.
+
+use date_time_tuple::DateTimeTuple;
+use error::Error;
+use posix_tz::PosixTz;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::str::{self, FromStr};
+use std::sync::{Arc, RwLock};
+use utc_offset::UtcOffset;
+
+lazy_static! {
+    static ref ZONEINFO_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+    static ref LOCAL_TIME_ZONE: TimeZone = find_local_time_zone();
+}
+
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+const LOCALTIME_PATH: &str = "/etc/localtime";
+
+/// A day either side of a local time covers every UTC instant which could show it,
+/// as offsets are at most 18 hours.
+const SEARCH_WINDOW_SECONDS: i64 = 86400;
+
+/// Sets the directory from which `TimeZone::load()` reads TZif files for the rest of
+/// the program, taking precedence over the `TZDIR` environment variable.
+pub fn set_zoneinfo_dir<P: Into<PathBuf>>(dir: P) {
+    *ZONEINFO_DIR.write().unwrap() = Some(dir.into());
+}
+
+/// Gets the directory from which `TimeZone::load()` reads TZif files.
+pub fn zoneinfo_dir() -> PathBuf {
+    if let Some(dir) = ZONEINFO_DIR.read().unwrap().as_ref() {
+        return dir.clone();
+    }
+    match env::var_os("TZDIR") {
+        Some(ref dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(DEFAULT_ZONEINFO_DIR),
+    }
+}
+
+/// An offset from UTC observed by a time zone, such as Central European Summer Time.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct LocalTimeType {
+    offset: UtcOffset,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+impl LocalTimeType {
+    pub(crate) fn new(offset: UtcOffset, is_dst: bool, abbreviation: String) -> LocalTimeType {
+        LocalTimeType {
+            offset,
+            is_dst,
+            abbreviation,
+        }
+    }
+
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Checks whether this is daylight saving (summer) time.
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Gets the abbreviation used for this type, such as CEST.
+    pub fn get_abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
+
+/// The instants in UTC at which a time zone's clocks show a local time, in Unix seconds.
+pub(crate) enum LocalInstants {
+    Single(i64),
+    /// The earlier and later instants of a local time repeated when clocks go back.
+    Ambiguous(i64, i64),
+    /// A local time skipped when clocks go forward, holding the instant they did so.
+    Skipped(i64),
+}
+
+/// A span of time with one local time type, between transitions in Unix seconds.
+/// The first period has no start and the last has no end.
+struct Period<'a> {
+    start: Option<i64>,
+    end: Option<i64>,
+    local_time_type: &'a LocalTimeType,
+}
+
+impl<'a> Period<'a> {
+    /// Gets the instant in UTC at which this period's offset shows the local time,
+    /// if it falls within the period.
+    fn instant_of(&self, local: i64) -> Option<i64> {
+        let instant = local - i64::from(self.local_time_type.offset.to_seconds());
+        match (self.start, self.end) {
+            (Some(start), _) if instant < start => None,
+            (_, Some(end)) if instant >= end => None,
+            _ => Some(instant),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct ZoneData {
+    name: String,
+    /// Transition instants in Unix seconds, in ascending order.
+    transitions: Vec<i64>,
+    /// The index into `types` which each transition switches to.
+    type_indices: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    footer: Option<PosixTz>,
+}
+
+/// A time zone with its history of UTC offsets, loaded from a TZif file.
+///
+/// Cloning a TimeZone is cheap, as the data is shared.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TimeZone {
+    data: Arc<ZoneData>,
+}
+
+impl TimeZone {
+    /// Loads a time zone by name, such as `Europe/Berlin`, from the zoneinfo directory.
+    ///
+    /// Fails with `Error::UnknownTimeZone` if there is no such zone, or
+    /// `Error::InvalidTimeZone` if its file cannot be read or is malformed.
+    pub fn load(name: &str) -> Result<TimeZone, Error> {
+        TimeZone::load_from(zoneinfo_dir(), name)
+    }
+
+    /// Loads a time zone by name from the given zoneinfo directory.
+    ///
+    /// Names must be relative paths within the directory, so names such as
+    /// `../etc/passwd` are rejected with `Error::UnknownTimeZone`.
+    pub fn load_from<P: AsRef<Path>>(dir: P, name: &str) -> Result<TimeZone, Error> {
+        let unknown = || Error::UnknownTimeZone {
+            name: name.to_string(),
+        };
+        let path = Path::new(name);
+        let is_relative = path.components().all(|c| matches!(c, Component::Normal(_)));
+        if name.is_empty() || !is_relative {
+            return Err(unknown());
+        }
+        TimeZone::read_file(&dir.as_ref().join(path), name)
+    }
+
+    /// Gets the system's local time zone. This is found from the `TZ` environment variable
+    /// as `TimeZone::from_tz_env()` reads it if it is set, or else from `/etc/localtime`.
+    /// If neither gives a valid zone, UTC is used.
+    ///
+    /// The zone is looked up on first use and cached for the rest of the program, so
+    /// later changes to `TZ` or to the zoneinfo directory have no effect on it.
+    pub fn local() -> TimeZone {
+        LOCAL_TIME_ZONE.clone()
+    }
+
+    /// Resolves a time zone from a value of the `TZ` environment variable.
+    ///
+    /// An empty value means UTC. A value starting with `:` names a TZif file, either by
+    /// an absolute path or relative to the zoneinfo directory. Any other value is loaded
+    /// as a zone name if the zoneinfo directory has it, or else read as a POSIX TZ string
+    /// such as `CET-1CEST,M3.5.0,M10.5.0/3`. Values without digits, which cannot be POSIX
+    /// TZ strings, fail with `Error::UnknownTimeZone` if no such zone is found.
+    pub fn from_tz_env(tz: &str) -> Result<TimeZone, Error> {
+        if tz.is_empty() {
+            return Ok(TimeZone::utc());
+        }
+        if let Some(file) = tz.strip_prefix(':') {
+            return if Path::new(file).is_absolute() {
+                TimeZone::read_file(Path::new(file), file)
+            } else {
+                TimeZone::load(file)
+            };
+        }
+        match TimeZone::load(tz) {
+            // A POSIX TZ string always has an offset, so a value without any digits is
+            // more likely to be a misspelt zone name.
+            Err(unknown @ Error::UnknownTimeZone { .. }) => {
+                if tz.contains(|c: char| c.is_ascii_digit()) {
+                    TimeZone::from_posix_tz(tz)
+                } else {
+                    Err(unknown)
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Reads a time zone from the contents of a TZif file, giving it the name provided.
+    ///
+    /// Fails with `Error::InvalidTimeZone` if the data is malformed.
+    pub fn from_tzif(name: &str, bytes: &[u8]) -> Result<TimeZone, Error> {
+        match read_tzif(name, bytes) {
+            Ok(data) => Ok(TimeZone {
+                data: Arc::new(data),
+            }),
+            Err(reason) => Err(Error::InvalidTimeZone {
+                name: name.to_string(),
+                reason: reason.to_string(),
+            }),
+        }
+    }
+
+    /// Produces a time zone from a POSIX TZ string alone, such as the `TZ` environment
+    /// variable of a system without a zoneinfo database. The zone is named by the string.
+    ///
+    /// Fails with `Error::ParseError` if the string is malformed.
+    pub fn from_posix_tz(tz: &str) -> Result<TimeZone, Error> {
+        let rules = PosixTz::from_str(tz)?;
+        Ok(TimeZone {
+            data: Arc::new(ZoneData {
+                name: tz.to_string(),
+                transitions: Vec::new(),
+                type_indices: Vec::new(),
+                types: vec![rules.get_standard().clone()],
+                footer: Some(rules),
+            }),
+        })
+    }
+
+    /// Produces the UTC time zone, which never changes its offset.
+    pub fn utc() -> TimeZone {
+        TimeZone {
+            data: Arc::new(ZoneData {
+                name: "UTC".to_string(),
+                transitions: Vec::new(),
+                type_indices: Vec::new(),
+                types: vec![LocalTimeType {
+                    offset: UtcOffset::utc(),
+                    is_dst: false,
+                    abbreviation: "UTC".to_string(),
+                }],
+                footer: None,
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.data.name
+    }
+
+    /// Gets the rules from the footer of a version 2 or later file, such as
+    /// `CET-1CEST,M3.5.0,M10.5.0/3`, which describe local time after the last transition.
+    pub fn get_posix_tz(&self) -> Option<&PosixTz> {
+        self.data.footer.as_ref()
+    }
+
+    /// Gets the local time type in effect at an instant in UTC.
+    pub fn local_time_type_at(&self, utc: DateTimeTuple) -> &LocalTimeType {
+        let data = &*self.data;
+        let instant = utc.to_unix_seconds();
+        let count = data.transitions.partition_point(|&t| t <= instant);
+        match data.footer {
+            Some(ref footer) if count == data.transitions.len() => {
+                footer.local_time_type_at_unix(instant)
+            }
+            _ => self.period(count).local_time_type,
+        }
+    }
+
+    /// Finds the instants in UTC at which this zone's clocks show a local time,
+    /// where both are given in Unix seconds.
+    pub(crate) fn instants_of(&self, local: i64) -> LocalInstants {
+        let periods =
+            self.periods_between(local - SEARCH_WINDOW_SECONDS, local + SEARCH_WINDOW_SECONDS);
+        let instants: Vec<i64> = periods.iter().filter_map(|p| p.instant_of(local)).collect();
+        match instants.len() {
+            0 => {
+                // The instant for each period's offset falls after the period until one
+                // falls before it, so the local time is skipped by that transition.
+                let skipped_by = periods
+                    .iter()
+                    .skip(1)
+                    .filter_map(|p| p.start.map(|start| (start, p)))
+                    .find(|&(start, p)| {
+                        local - i64::from(p.local_time_type.offset.to_seconds()) < start
+                    })
+                    .map(|(start, _)| start)
+                    .unwrap();
+                LocalInstants::Skipped(skipped_by)
+            }
+            1 => LocalInstants::Single(instants[0]),
+            n => LocalInstants::Ambiguous(instants[0], instants[n - 1]),
+        }
+    }
+
+    fn read_file(path: &Path, name: &str) -> Result<TimeZone, Error> {
+        match fs::read(path) {
+            Ok(bytes) => TimeZone::from_tzif(name, &bytes),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Err(Error::UnknownTimeZone {
+                name: name.to_string(),
+            }),
+            Err(e) => Err(Error::InvalidTimeZone {
+                name: name.to_string(),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    /// Gets the periods overlapping a span of time given in Unix seconds, in order.
+    fn periods_between(&self, from: i64, to: i64) -> Vec<Period<'_>> {
+        let data = &*self.data;
+        let first = data.transitions.partition_point(|&t| t <= from);
+        let last = data.transitions.partition_point(|&t| t <= to);
+        let mut periods: Vec<Period<'_>> = (first..=last).map(|i| self.period(i)).collect();
+        let footer = match data.footer {
+            Some(ref footer) if last == data.transitions.len() => footer,
+            _ => return periods,
+        };
+        // The footer takes over from the last transition, splitting the final period.
+        let mut start = periods.pop().unwrap().start;
+        let footer_from = start.map_or(from, |start| start.max(from));
+        let mut local_time_type = footer.local_time_type_at_unix(footer_from);
+        for (at, next) in footer.transitions_between(footer_from, to) {
+            periods.push(Period {
+                start,
+                end: Some(at),
+                local_time_type,
+            });
+            start = Some(at);
+            local_time_type = next;
+        }
+        periods.push(Period {
+            start,
+            end: None,
+            local_time_type,
+        });
+        periods
+    }
+
+    /// Gets the period following the given number of transitions.
+    fn period(&self, index: usize) -> Period<'_> {
+        let data = &*self.data;
+        Period {
+            start: if index == 0 {
+                None
+            } else {
+                Some(data.transitions[index - 1])
+            },
+            end: data.transitions.get(index).cloned(),
+            local_time_type: if index == 0 {
+                &data.types[0]
+            } else {
+                &data.types[data.type_indices[index - 1]]
+            },
+        }
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.data.name)
+    }
+}
+
+fn find_local_time_zone() -> TimeZone {
+    let found = match env::var("TZ") {
+        Ok(tz) => TimeZone::from_tz_env(&tz),
+        Err(_) => {
+            let path = Path::new(LOCALTIME_PATH);
+            TimeZone::read_file(path, &local_time_zone_name(path))
+        }
+    };
+    found.unwrap_or_else(|_| TimeZone::utc())
+}
+
+/// Names the zone of `/etc/localtime` after the zoneinfo file it links to, such as
+/// `Europe/Berlin`, or else as `localtime`.
+fn local_time_zone_name(path: &Path) -> String {
+    fs::read_link(path)
+        .ok()
+        .and_then(|target| {
+            let target = target.to_string_lossy().into_owned();
+            target
+                .rfind("zoneinfo/")
+                .map(|i| target[i + "zoneinfo/".len()..].to_string())
+        })
+        .unwrap_or_else(|| "localtime".to_string())
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    /// Gets the length of the data block following this header, given the size
+    /// of its transition times, or `None` if it is too large to be held in memory.
+    fn block_len(&self, time_size: usize) -> Option<usize> {
+        [
+            self.timecnt.checked_mul(time_size + 1)?,
+            self.typecnt.checked_mul(6)?,
+            self.charcnt,
+            self.leapcnt.checked_mul(time_size + 4)?,
+            self.isstdcnt,
+            self.isutcnt,
+        ]
+        .iter()
+        .try_fold(0usize, |total, &len| total.checked_add(len))
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], &'static str> {
+        if n > self.bytes.len() {
+            return Err("the file ends unexpectedly");
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, &'static str> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, &'static str> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn read_i32(&mut self) -> Result<i32, &'static str> {
+        Ok(self.read_u32()? as i32)
+    }
+
+    fn read_i64(&mut self) -> Result<i64, &'static str> {
+        let b = self.take(8)?;
+        Ok(i64::from_be_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7],
+        ]))
+    }
+}
+
+fn read_tzif(name: &str, bytes: &[u8]) -> Result<ZoneData, &'static str> {
+    let mut reader = Reader { bytes };
+    let header = read_header(&mut reader, 4)?;
+    let (header, time_size) = if header.version >= 2 {
+        // The version 1 data is only there for older readers.
+        reader.take(header.block_len(4).ok_or("the file ends unexpectedly")?)?;
+        (read_header(&mut reader, 8)?, 8)
+    } else {
+        (header, 4)
+    };
+
+    let mut transitions = Vec::with_capacity(header.timecnt);
+    for _ in 0..header.timecnt {
+        transitions.push(if time_size == 8 {
+            reader.read_i64()?
+        } else {
+            i64::from(reader.read_i32()?)
+        });
+    }
+    if transitions.windows(2).any(|w| w[0] >= w[1]) {
+        return Err("the transition times are not in ascending order");
+    }
+    let mut type_indices = Vec::with_capacity(header.timecnt);
+    for _ in 0..header.timecnt {
+        let index = reader.read_u8()? as usize;
+        if index >= header.typecnt {
+            return Err("a transition refers to a local time type which does not exist");
+        }
+        type_indices.push(index);
+    }
+    let mut raw_types = Vec::with_capacity(header.typecnt);
+    for _ in 0..header.typecnt {
+        raw_types.push((reader.read_i32()?, reader.read_u8()?, reader.read_u8()?));
+    }
+    let chars = reader.take(header.charcnt)?;
+    let mut types = Vec::with_capacity(header.typecnt);
+    for (utoff, isdst, desigidx) in raw_types {
+        let offset = UtcOffset::from_seconds(utoff)
+            .map_err(|_| "a local time type has an offset of more than 18 hours")?;
+        if isdst > 1 {
+            return Err("a local time type has an invalid daylight saving time indicator");
+        }
+        let abbreviation = chars
+            .get(desigidx as usize..)
+            .and_then(|rest| rest.iter().position(|&c| c == 0).map(|end| &rest[..end]))
+            .ok_or("a local time type has an invalid abbreviation")?;
+        types.push(LocalTimeType {
+            offset,
+            is_dst: isdst == 1,
+            abbreviation: String::from_utf8_lossy(abbreviation).into_owned(),
+        });
+    }
+    reader.take(header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt)?;
+
+    let footer = if header.version >= 2 {
+        if reader.read_u8()? != b'\n' {
+            return Err("the footer does not start with a newline");
+        }
+        let end = reader
+            .bytes
+            .iter()
+            .position(|&c| c == b'\n')
+            .ok_or("the footer does not end with a newline")?;
+        let footer = str::from_utf8(reader.take(end)?).map_err(|_| "the footer is not ASCII")?;
+        if footer.is_empty() {
+            None
+        } else {
+            Some(
+                PosixTz::from_str(footer)
+                    .map_err(|_| "the footer is not a valid POSIX TZ string")?,
+            )
+        }
+    } else {
+        None
+    };
+
+    Ok(ZoneData {
+        name: name.to_string(),
+        transitions,
+        type_indices,
+        types,
+        footer,
+    })
+}
+
+/// Reads a header, checking that the data block it describes, with transition times
+/// of `time_size` bytes, is all there before anything is allocated for it.
+fn read_header(reader: &mut Reader, time_size: usize) -> Result<Header, &'static str> {
+    if reader.take(4)? != b"TZif" {
+        return Err("it is not a TZif file");
+    }
+    let version = match reader.read_u8()? {
+        0 => 1,
+        b'2' => 2,
+        b'3' => 3,
+        _ => return Err("its TZif version is not supported"),
+    };
+    reader.take(15)?;
+    let header = Header {
+        version,
+        isutcnt: reader.read_u32()? as usize,
+        isstdcnt: reader.read_u32()? as usize,
+        leapcnt: reader.read_u32()? as usize,
+        timecnt: reader.read_u32()? as usize,
+        typecnt: reader.read_u32()? as usize,
+        charcnt: reader.read_u32()? as usize,
+    };
+    if header.typecnt == 0 || header.charcnt == 0 {
+        return Err("it has no local time types");
+    }
+    if (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+        || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt)
+    {
+        return Err("its header counts are inconsistent");
+    }
+    match header.block_len(time_size) {
+        Some(len) if len <= reader.bytes.len() => Ok(header),
+        _ => Err("the file ends unexpectedly"),
+    }
+}
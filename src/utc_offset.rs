@@ -0,0 +1,183 @@
+use error::{Error, TypeName};
+use regex::Regex;
+use std::cmp::Ordering;
+#[cfg(feature = "serde_support")]
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// The largest offset from UTC accepted, in seconds: 18 hours either way.
+const MAX_OFFSET_SECONDS: i32 = 18 * 3600;
+
+/// A fixed offset from UTC, such as +02:00 or -05:30.
+///
+/// Offsets are positive east of Greenwich, and may be up to 18 hours either way.
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UtcOffsetFields")
+)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct UtcOffset {
+    s: i32,
+}
+
+/// The fields of a serialised UtcOffset, which are checked before use.
+#[cfg(feature = "serde_support")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "UtcOffset")]
+struct UtcOffsetFields {
+    s: i32,
+}
+
+#[cfg(feature = "serde_support")]
+impl TryFrom<UtcOffsetFields> for UtcOffset {
+    type Error = Error;
+
+    fn try_from(fields: UtcOffsetFields) -> Result<UtcOffset, Error> {
+        UtcOffset::from_seconds(fields.s)
+    }
+}
+
+impl UtcOffset {
+    /// Produces an offset from its hours, minutes, and seconds, which must not have
+    /// differing signs: -05:30 is `UtcOffset::new(-5, -30, 0)`.
+    ///
+    /// Fails if the minutes or seconds are outside of -59 to 59, the signs differ,
+    /// or the offset is more than 18 hours.
+    pub fn new(hours: i8, minutes: i8, seconds: i8) -> Result<UtcOffset, Error> {
+        let seconds_total = i32::from(hours) * 3600 + i32::from(minutes) * 60 + i32::from(seconds);
+        let signs_differ = [hours, minutes, seconds]
+            .iter()
+            .any(|n| n.signum() != 0 && n.signum() != seconds_total.signum() as i8);
+        if !(-59..=59).contains(&minutes) || !(-59..=59).contains(&seconds) || signs_differ {
+            return Err(Error::InvalidOffset {
+                seconds: seconds_total,
+            });
+        }
+        UtcOffset::from_seconds(seconds_total)
+    }
+
+    /// Produces an offset from a number of seconds east of UTC.
+    ///
+    /// Fails if the offset is more than 18 hours.
+    pub fn from_seconds(seconds: i32) -> Result<UtcOffset, Error> {
+        if !(-MAX_OFFSET_SECONDS..=MAX_OFFSET_SECONDS).contains(&seconds) {
+            Err(Error::InvalidOffset { seconds })
+        } else {
+            Ok(UtcOffset { s: seconds })
+        }
+    }
+
+    /// Returns the offset of UTC itself, +00:00.
+    pub fn utc() -> UtcOffset {
+        UtcOffset { s: 0 }
+    }
+
+    /// Returns the largest offset west of UTC, -18:00.
+    pub fn min_value() -> UtcOffset {
+        UtcOffset {
+            s: -MAX_OFFSET_SECONDS,
+        }
+    }
+
+    /// Returns the largest offset east of UTC, +18:00.
+    pub fn max_value() -> UtcOffset {
+        UtcOffset {
+            s: MAX_OFFSET_SECONDS,
+        }
+    }
+
+    /// Gets the whole hours of the offset, which are negative west of UTC.
+    pub fn get_hours(self) -> i8 {
+        (self.s / 3600) as i8
+    }
+
+    /// Gets the minutes of the offset after the whole hours, which are negative west of UTC.
+    pub fn get_minutes(self) -> i8 {
+        (self.s / 60 % 60) as i8
+    }
+
+    /// Gets the seconds of the offset after the whole minutes, which are negative west of UTC.
+    pub fn get_seconds(self) -> i8 {
+        (self.s % 60) as i8
+    }
+
+    /// Gets the total number of seconds east of UTC.
+    pub fn to_seconds(self) -> i32 {
+        self.s
+    }
+
+    pub fn is_utc(self) -> bool {
+        self.s == 0
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.s < 0
+    }
+}
+
+impl Default for UtcOffset {
+    fn default() -> UtcOffset {
+        UtcOffset::utc()
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    /// Formats the offset like +02:00 or -05:30, adding seconds only if there are any,
+    /// as in +00:19:32.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.s < 0 { '-' } else { '+' };
+        let s = self.s.abs();
+        write!(f, "{}{:02}:{:02}", sign, s / 3600, s / 60 % 60)?;
+        if s % 60 != 0 {
+            write!(f, ":{:02}", s % 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = Error;
+
+    /// Expects an offset like +02:00, -0530, +05, or +00:19:32, or Z for UTC.
+    fn from_str(s: &str) -> Result<UtcOffset, Self::Err> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex =
+                Regex::new(r"^([+-])(\d{2})(?::?(\d{2})(?::?(\d{2}))?)?$").unwrap();
+        }
+
+        if s == "Z" || s == "z" {
+            return Ok(UtcOffset::utc());
+        }
+        let invalid = || Error::InvalidFormat {
+            type_name: TypeName::UtcOffset,
+            input: s.to_string(),
+            expected: "+02:00",
+        };
+        let caps = VALID_FORMAT.captures(s).ok_or_else(invalid)?;
+        let number = |i: usize| {
+            caps.get(i)
+                .map_or(0, |m| i32::from_str(m.as_str()).unwrap())
+        };
+        let (hours, minutes, seconds) = (number(2), number(3), number(4));
+        if minutes > 59 || seconds > 59 {
+            return Err(invalid());
+        }
+        let total = hours * 3600 + minutes * 60 + seconds;
+        UtcOffset::from_seconds(if &caps[1] == "-" { -total } else { total })
+    }
+}
+
+impl PartialOrd for UtcOffset {
+    fn partial_cmp(&self, other: &UtcOffset) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for UtcOffset {
+    fn cmp(&self, other: &UtcOffset) -> Ordering {
+        self.s.cmp(&other.s)
+    }
+}
@@ -0,0 +1,255 @@
+use date_tuple::DateTuple;
+use date_utils;
+use error::{Error, TypeName};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use weekday::Weekday;
+
+pub type Week = WeekTuple;
+
+/// A container for an ISO 8601 week of a specific week-numbering year.
+///
+/// ISO weeks start on a Monday, and the first week of a year is the one containing
+/// its first Thursday. This means the week-numbering year can differ from the calendar
+/// year for dates near the start and end of a year: 2018-12-31 falls in 2019-W01, for example.
+///
+/// Only handles values between 0000-W01 and 9999-W52 (inclusive).
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct WeekTuple {
+    y: u16,
+    w: u8,
+}
+
+impl WeekTuple {
+    /// Produces a new WeekTuple.
+    ///
+    /// Only accepts a valid year value (`0 <= y <= 9999`).
+    ///
+    /// Only accepts a week which exists in that year (`1 <= w <= 52`, or `53` in long years).
+    pub fn new(y: u16, w: u8) -> Result<WeekTuple, Error> {
+        if y > 9999 {
+            return Err(Error::InvalidYear {
+                type_name: TypeName::WeekTuple,
+                value: format!("{:?}", WeekTuple { y, w }),
+            });
+        }
+        if w == 0 || w > WeekTuple::weeks_in_year(y) {
+            return Err(Error::InvalidWeek {
+                type_name: TypeName::WeekTuple,
+                value: format!("{:?}", WeekTuple { y, w }),
+                weeks_in_year: WeekTuple::weeks_in_year(y),
+            });
+        }
+        Ok(WeekTuple { y, w })
+    }
+
+    /// Returns the minimum week handled - 0000-W01.
+    pub fn min_value() -> WeekTuple {
+        WeekTuple { y: 0, w: 1 }
+    }
+
+    /// Returns the maximum week handled - 9999-W52.
+    pub fn max_value() -> WeekTuple {
+        WeekTuple { y: 9999, w: 52 }
+    }
+
+    /// Gets the number of ISO weeks in a week-numbering year, either 52 or 53.
+    ///
+    /// Years starting on a Thursday, and leap years starting on a Wednesday, have 53 weeks.
+    pub fn weeks_in_year(y: u16) -> u8 {
+        match DateTuple::new(y, 1, 1).map(DateTuple::weekday) {
+            Ok(Weekday::Thursday) => 53,
+            Ok(Weekday::Wednesday) if date_utils::is_leap_year(i64::from(y)) => 53,
+            _ => 52,
+        }
+    }
+
+    /// Gets the ISO week-numbering year, which may differ from the calendar
+    /// year of some dates within the week.
+    pub fn get_year(self) -> u16 {
+        self.y
+    }
+
+    pub fn get_week(self) -> u8 {
+        self.w
+    }
+
+    /// Gets a WeekTuple representing the week immediately following
+    /// the current one. Will not go past 9999-W52.
+    pub fn next_week(self) -> WeekTuple {
+        if self == WeekTuple::max_value() {
+            return self;
+        }
+        if self.w == WeekTuple::weeks_in_year(self.y) {
+            WeekTuple {
+                y: self.y + 1,
+                w: 1,
+            }
+        } else {
+            WeekTuple {
+                y: self.y,
+                w: self.w + 1,
+            }
+        }
+    }
+
+    /// Gets a WeekTuple representing the week immediately preceding
+    /// the current one. Will not go past 0000-W01.
+    pub fn previous_week(self) -> WeekTuple {
+        if self == WeekTuple::min_value() {
+            return self;
+        }
+        if self.w == 1 {
+            WeekTuple {
+                y: self.y - 1,
+                w: WeekTuple::weeks_in_year(self.y - 1),
+            }
+        } else {
+            WeekTuple {
+                y: self.y,
+                w: self.w - 1,
+            }
+        }
+    }
+
+    /// Gets the date of the Monday which starts the week.
+    pub fn get_first_date(self) -> DateTuple {
+        // 4 Jan is always in week 1.
+        let jan_4 = DateTuple::new(self.y, 1, 4).unwrap();
+        let first_monday = jan_4.to_days() - u32::from(jan_4.weekday().number_from_monday()) + 1;
+        DateTuple::from_days(first_monday + 7 * (u32::from(self.w) - 1)).unwrap()
+    }
+
+    /// Gets the date of the given day within the week.
+    ///
+    /// Fails for the weekend of 9999-W52, which falls in the year 10000.
+    pub fn to_date(self, weekday: Weekday) -> Result<DateTuple, Error> {
+        let days = self.get_first_date().to_days() + u32::from(weekday.number_from_monday()) - 1;
+        DateTuple::from_days(days).map_err(|_| Error::OutOfRange {
+            type_name: TypeName::DateTuple,
+            value: format!("{}-{}", self, weekday.number_from_monday()),
+            min: DateTuple::min_value().to_string(),
+            max: DateTuple::max_value().to_string(),
+        })
+    }
+}
+
+impl fmt::Display for WeekTuple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-W{:02}", self.y, self.w)
+    }
+}
+
+impl FromStr for WeekTuple {
+    type Err = Error;
+
+    /// Expects a string formatted like 2018-W07.
+    ///
+    /// Also accepts the ISO 8601 basic format of 2018W07.
+    fn from_str(s: &str) -> Result<WeekTuple, Self::Err> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex = Regex::new(r"^(\d{4})-?W(\d{2})$").unwrap();
+        }
+
+        match VALID_FORMAT.captures(s) {
+            Some(caps) => WeekTuple::new(
+                u16::from_str(&caps[1]).unwrap(),
+                u8::from_str(&caps[2]).unwrap(),
+            )
+            .map_err(|e| Error::InvalidParsedValue {
+                type_name: TypeName::WeekTuple,
+                error: Box::new(e),
+            }),
+            None => Err(Error::InvalidFormat {
+                type_name: TypeName::WeekTuple,
+                input: s.to_string(),
+                expected: "2018-W07",
+            }),
+        }
+    }
+}
+
+impl PartialOrd for WeekTuple {
+    fn partial_cmp(&self, other: &WeekTuple) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for WeekTuple {
+    fn cmp(&self, other: &WeekTuple) -> Ordering {
+        if self.y == other.y {
+            self.w.cmp(&other.w)
+        } else {
+            self.y.cmp(&other.y)
+        }
+    }
+}
+
+impl TryFrom<DateTuple> for WeekTuple {
+    type Error = Error;
+
+    /// Finds the ISO week containing a date.
+    ///
+    /// Fails for 1 and 2 Jan 0000, which fall in the last week of the year before 0000.
+    fn try_from(date: DateTuple) -> Result<Self, Self::Error> {
+        let y = date.get_year();
+        let day_of_year = u32::from(date.day_of_year());
+        let weekday = u32::from(date.weekday().number_from_monday());
+        let w = (day_of_year + 10 - weekday) / 7;
+        if w == 0 {
+            if y == 0 {
+                return Err(Error::OutOfRange {
+                    type_name: TypeName::WeekTuple,
+                    value: date.to_string(),
+                    min: WeekTuple::min_value().get_first_date().to_string(),
+                    max: DateTuple::max_value().to_string(),
+                });
+            }
+            Ok(WeekTuple {
+                y: y - 1,
+                w: WeekTuple::weeks_in_year(y - 1),
+            })
+        } else if w > u32::from(WeekTuple::weeks_in_year(y)) {
+            Ok(WeekTuple { y: y + 1, w: 1 })
+        } else {
+            Ok(WeekTuple { y, w: w as u8 })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::WeekTuple;
+
+    #[test]
+    fn test_next_week() {
+        let tuple1 = WeekTuple::new(2020, 52).unwrap();
+        let tuple2 = WeekTuple::new(2020, 53).unwrap();
+        let tuple3 = WeekTuple::new(2021, 52).unwrap();
+        assert_eq!(WeekTuple { y: 2020, w: 53 }, tuple1.next_week());
+        assert_eq!(WeekTuple { y: 2021, w: 1 }, tuple2.next_week());
+        assert_eq!(WeekTuple { y: 2022, w: 1 }, tuple3.next_week());
+        assert_eq!(WeekTuple::max_value(), WeekTuple::max_value().next_week());
+    }
+
+    #[test]
+    fn test_previous_week() {
+        let tuple1 = WeekTuple::new(2021, 1).unwrap();
+        let tuple2 = WeekTuple::new(2022, 1).unwrap();
+        assert_eq!(WeekTuple { y: 2020, w: 53 }, tuple1.previous_week());
+        assert_eq!(WeekTuple { y: 2021, w: 52 }, tuple2.previous_week());
+        assert_eq!(
+            WeekTuple::min_value(),
+            WeekTuple::min_value().previous_week()
+        );
+    }
+}
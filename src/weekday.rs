@@ -0,0 +1,140 @@
+use error::{Error, TypeName};
+use std::fmt;
+use std::str::FromStr;
+
+pub(crate) const WEEKDAY_STRINGS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// A day of the week.
+///
+/// Weeks start on Monday, following ISO 8601.
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Produces the weekday with the given number of days since Monday.
+    ///
+    /// Wraps every seven days, so 7 is another Monday.
+    pub(crate) fn from_days_from_monday(days: u32) -> Weekday {
+        match days % 7 {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// Produces a weekday from its ISO 8601 number, where 1 is Monday and 7 is Sunday.
+    pub fn from_number_from_monday(n: u8) -> Result<Weekday, Error> {
+        if (1..=7).contains(&n) {
+            Ok(Weekday::from_days_from_monday(u32::from(n) - 1))
+        } else {
+            Err(Error::InvalidWeekday {
+                value: n,
+                from_sunday: false,
+            })
+        }
+    }
+
+    /// Produces a weekday from its number counting from Sunday, where 0 is Sunday and 6 is Saturday.
+    pub fn from_number_from_sunday(n: u8) -> Result<Weekday, Error> {
+        if n <= 6 {
+            Ok(Weekday::from_days_from_monday(u32::from(n) + 6))
+        } else {
+            Err(Error::InvalidWeekday {
+                value: n,
+                from_sunday: true,
+            })
+        }
+    }
+
+    /// Gets the ISO 8601 number of the weekday, where 1 is Monday and 7 is Sunday.
+    pub fn number_from_monday(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Gets the number of the weekday counting from Sunday, where 0 is Sunday and 6 is Saturday.
+    pub fn number_from_sunday(self) -> u8 {
+        (self as u8 + 1) % 7
+    }
+
+    /// Gets the weekday immediately following this one, wrapping from Sunday to Monday.
+    pub fn succ(self) -> Weekday {
+        Weekday::from_days_from_monday(self as u32 + 1)
+    }
+
+    /// Gets the weekday immediately preceding this one, wrapping from Monday to Sunday.
+    pub fn pred(self) -> Weekday {
+        Weekday::from_days_from_monday(self as u32 + 6)
+    }
+
+    /// Produces the three-letter abbreviation of the weekday.
+    ///
+    /// ## Examples
+    /// * Mon
+    /// * Thu
+    pub fn to_short_string(self) -> String {
+        WEEKDAY_STRINGS[self as usize][..3].to_string()
+    }
+}
+
+/// Produces the full English name of the weekday, such as Monday.
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", WEEKDAY_STRINGS[*self as usize])
+    }
+}
+
+impl FromStr for Weekday {
+    type Err = Error;
+
+    /// Accepts either the full name (Monday) or the three-letter
+    /// abbreviation (Mon) of the weekday, ignoring case.
+    fn from_str(s: &str) -> Result<Weekday, Self::Err> {
+        for (i, name) in WEEKDAY_STRINGS.iter().enumerate() {
+            if s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]) {
+                return Ok(Weekday::from_days_from_monday(i as u32));
+            }
+        }
+        Err(Error::InvalidFormat {
+            type_name: TypeName::Weekday,
+            input: s.to_string(),
+            expected: "Monday or Mon",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Weekday;
+
+    #[test]
+    fn test_from_days_from_monday_wraps() {
+        assert_eq!(Weekday::Monday, Weekday::from_days_from_monday(0));
+        assert_eq!(Weekday::Sunday, Weekday::from_days_from_monday(6));
+        assert_eq!(Weekday::Monday, Weekday::from_days_from_monday(7));
+    }
+}
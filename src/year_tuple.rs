@@ -0,0 +1,181 @@
+use clock::Clock;
+use date_tuple::DateTuple;
+use date_utils;
+use error::{Error, TypeName};
+use month_tuple::MonthTuple;
+use quarter_tuple::QuarterTuple;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::convert::From;
+use std::fmt;
+use std::str::FromStr;
+
+pub type Year = YearTuple;
+
+/// A container for a specific year.
+///
+/// Only handles values between 0000 and 9999 (inclusive).
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct YearTuple {
+    y: u16,
+}
+
+impl YearTuple {
+    /// Produces a new YearTuple.
+    ///
+    /// Only accepts a valid year value (`0 <= y <= 9999`).
+    pub fn new(y: u16) -> Result<YearTuple, Error> {
+        if y <= 9999 {
+            Ok(YearTuple { y })
+        } else {
+            Err(Error::InvalidYear {
+                type_name: TypeName::YearTuple,
+                value: format!("{:?}", YearTuple { y }),
+            })
+        }
+    }
+
+    /// Returns a `YearTuple` of the current year according to the system clock.
+    pub fn this_year() -> YearTuple {
+        YearTuple::from(DateTuple::today())
+    }
+
+    /// Returns a `YearTuple` of the current year in UTC according to a clock.
+    pub fn this_year_with_clock<C: Clock + ?Sized>(clock: &C) -> YearTuple {
+        YearTuple::from(clock.now().get_date())
+    }
+
+    pub fn get_year(self) -> u16 {
+        self.y
+    }
+
+    /// Returns whether the year is a leap year.
+    pub fn is_leap_year(self) -> bool {
+        date_utils::is_leap_year(i64::from(self.y))
+    }
+
+    /// Gets a YearTuple representing the year immediately following
+    /// the current one. Will not go past 9999.
+    pub fn next_year(self) -> YearTuple {
+        if self.y == 9999 {
+            return self;
+        }
+        YearTuple { y: self.y + 1 }
+    }
+
+    /// Gets a YearTuple representing the year immediately preceding
+    /// the current one. Will not go past 0000.
+    pub fn previous_year(self) -> YearTuple {
+        if self.y == 0 {
+            return self;
+        }
+        YearTuple { y: self.y - 1 }
+    }
+
+    /// Adds a number of years to a YearTuple.
+    pub fn add_years(&mut self, years: u16) {
+        self.y = self.y.saturating_add(years).min(9999);
+    }
+
+    /// Subtracts a number of years from a YearTuple.
+    pub fn subtract_years(&mut self, years: u16) {
+        self.y = self.y.saturating_sub(years);
+    }
+
+    /// Gets the twelve months which make up the year, in order.
+    pub fn get_months(self) -> [MonthTuple; 12] {
+        let mut months = [MonthTuple::new(self.y, 1).unwrap(); 12];
+        for (i, month) in months.iter_mut().enumerate() {
+            *month = MonthTuple::new(self.y, i as u8 + 1).unwrap();
+        }
+        months
+    }
+
+    /// Gets the four quarters which make up the year, in order.
+    pub fn get_quarters(self) -> [QuarterTuple; 4] {
+        [
+            QuarterTuple::new(self.y, 1).unwrap(),
+            QuarterTuple::new(self.y, 2).unwrap(),
+            QuarterTuple::new(self.y, 3).unwrap(),
+            QuarterTuple::new(self.y, 4).unwrap(),
+        ]
+    }
+
+    /// Gets the first date in the year - 1st January.
+    pub fn get_first_date(self) -> DateTuple {
+        DateTuple::new(self.y, 1, 1).unwrap()
+    }
+
+    /// Gets the last date in the year - 31st December.
+    pub fn get_last_date(self) -> DateTuple {
+        DateTuple::new(self.y, 12, 31).unwrap()
+    }
+}
+
+impl fmt::Display for YearTuple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}", self.y)
+    }
+}
+
+impl FromStr for YearTuple {
+    type Err = Error;
+
+    /// Expects a four-digit year such as 2018.
+    fn from_str(s: &str) -> Result<YearTuple, Self::Err> {
+        lazy_static! {
+            static ref VALID_FORMAT: Regex = Regex::new(r"^\d{4}$").unwrap();
+        }
+
+        if VALID_FORMAT.is_match(s) {
+            Ok(YearTuple {
+                y: u16::from_str(s).unwrap(),
+            })
+        } else {
+            Err(Error::InvalidFormat {
+                type_name: TypeName::YearTuple,
+                input: s.to_string(),
+                expected: "2018",
+            })
+        }
+    }
+}
+
+impl PartialOrd for YearTuple {
+    fn partial_cmp(&self, other: &YearTuple) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for YearTuple {
+    fn cmp(&self, other: &YearTuple) -> Ordering {
+        self.y.cmp(&other.y)
+    }
+}
+
+impl From<DateTuple> for YearTuple {
+    fn from(date: DateTuple) -> Self {
+        YearTuple { y: date.get_year() }
+    }
+}
+
+impl From<MonthTuple> for YearTuple {
+    fn from(month: MonthTuple) -> Self {
+        YearTuple {
+            y: month.get_year(),
+        }
+    }
+}
+
+impl From<QuarterTuple> for YearTuple {
+    fn from(quarter: QuarterTuple) -> Self {
+        YearTuple {
+            y: quarter.get_year(),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use date_time_tuple::DateTimeTuple;
+use date_tuple::DateTuple;
+use error::{Error, TypeName};
+use offset_date_time::OffsetDateTime;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use time_tuple::TimeTuple;
+use tz::{LocalInstants, LocalTimeType, TimeZone};
+use utc_offset::UtcOffset;
+
+/// A date and time on the local clock of a time zone.
+///
+/// Like `OffsetDateTime`s, ZonedDateTimes are compared by the instant they represent,
+/// whatever their time zones.
+#[derive(Debug, Clone)]
+pub struct ZonedDateTime {
+    odt: OffsetDateTime,
+    zone: TimeZone,
+}
+
+/// The result of finding a local date and time in a time zone, which may occur once,
+/// twice, or not at all around a change of offset.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum LocalResult {
+    /// The local time occurs once.
+    Single(ZonedDateTime),
+    /// The local time occurs twice as the clocks go back, first at the earlier offset
+    /// and then at the later one.
+    Ambiguous(ZonedDateTime, ZonedDateTime),
+    /// The local time is skipped as the clocks go forward. This holds the instant at
+    /// which they do so, which is the first local time after the gap.
+    Skipped(ZonedDateTime),
+}
+
+impl LocalResult {
+    /// Gets the only matching date and time, or `None` if it is ambiguous or skipped.
+    pub fn single(self) -> Option<ZonedDateTime> {
+        match self {
+            LocalResult::Single(zdt) => Some(zdt),
+            _ => None,
+        }
+    }
+
+    /// Gets the earlier matching date and time, or `None` if it is skipped.
+    pub fn earliest(self) -> Option<ZonedDateTime> {
+        match self {
+            LocalResult::Single(zdt) | LocalResult::Ambiguous(zdt, _) => Some(zdt),
+            LocalResult::Skipped(_) => None,
+        }
+    }
+
+    /// Gets the later matching date and time, or `None` if it is skipped.
+    pub fn latest(self) -> Option<ZonedDateTime> {
+        match self {
+            LocalResult::Single(zdt) | LocalResult::Ambiguous(_, zdt) => Some(zdt),
+            LocalResult::Skipped(_) => None,
+        }
+    }
+}
+
+impl ZonedDateTime {
+    /// Produces the date and time in a time zone at an instant in UTC.
+    ///
+    /// Fails with `Error::OutOfRange` if the local date and time would fall outside
+    /// of the range of `DateTimeTuple`.
+    pub fn from_utc(utc: DateTimeTuple, zone: &TimeZone) -> Result<ZonedDateTime, Error> {
+        let offset = zone.local_time_type_at(utc).get_offset();
+        Ok(ZonedDateTime {
+            odt: OffsetDateTime::from_utc(utc, offset)?,
+            zone: zone.clone(),
+        })
+    }
+
+    /// Finds the instants at which a time zone's clocks show a local date and time.
+    ///
+    /// This gives `LocalResult::Ambiguous` for a time repeated when the clocks go back,
+    /// and `LocalResult::Skipped` for a time which never occurs as they go forward.
+    /// Fails with `Error::OutOfRange` if an instant would fall outside of the range
+    /// of `DateTimeTuple`.
+    pub fn from_local(local: DateTimeTuple, zone: &TimeZone) -> Result<LocalResult, Error> {
+        let at = |instant: i64| match DateTimeTuple::checked_from_unix_seconds(instant) {
+            Some(utc) => ZonedDateTime::from_utc(utc, zone),
+            None => Err(Error::OutOfRange {
+                type_name: TypeName::ZonedDateTime,
+                value: format!("{}[{}]", local.to_rfc3339(), zone),
+                min: DateTimeTuple::min_value().to_rfc3339(),
+                max: DateTimeTuple::max_value().to_rfc3339(),
+            }),
+        };
+        Ok(match zone.instants_of(local.to_unix_seconds()) {
+            LocalInstants::Single(instant) => LocalResult::Single(at(instant)?),
+            LocalInstants::Ambiguous(earlier, later) => {
+                LocalResult::Ambiguous(at(earlier)?, at(later)?)
+            }
+            LocalInstants::Skipped(transition) => LocalResult::Skipped(at(transition)?),
+        })
+    }
+
+    /// Gets the date and time on the local clock.
+    pub fn get_date_time(&self) -> DateTimeTuple {
+        self.odt.get_date_time()
+    }
+
+    /// Gets the date on the local clock.
+    pub fn get_date(&self) -> DateTuple {
+        self.odt.get_date()
+    }
+
+    /// Gets the time on the local clock.
+    pub fn get_time(&self) -> TimeTuple {
+        self.odt.get_time()
+    }
+
+    pub fn get_offset(&self) -> UtcOffset {
+        self.odt.get_offset()
+    }
+
+    pub fn get_time_zone(&self) -> &TimeZone {
+        &self.zone
+    }
+
+    /// Gets the abbreviation for the local time, such as CEST.
+    pub fn get_abbreviation(&self) -> &str {
+        self.local_time_type().get_abbreviation()
+    }
+
+    /// Checks whether the local time is daylight saving (summer) time.
+    pub fn is_dst(&self) -> bool {
+        self.local_time_type().is_dst()
+    }
+
+    /// Gets the same instant as a date and time in UTC.
+    pub fn to_utc(&self) -> DateTimeTuple {
+        self.odt.to_utc()
+    }
+
+    /// Gets the local date and time with its current offset, forgetting the time zone.
+    pub fn to_offset_date_time(&self) -> OffsetDateTime {
+        self.odt
+    }
+
+    /// Gets the same instant in a different time zone.
+    ///
+    /// Fails with `Error::OutOfRange` if the local date and time in that zone would
+    /// fall outside of the range of `DateTimeTuple`.
+    pub fn with_time_zone(&self, zone: &TimeZone) -> Result<ZonedDateTime, Error> {
+        ZonedDateTime::from_utc(self.to_utc(), zone)
+    }
+
+    fn local_time_type(&self) -> &LocalTimeType {
+        self.zone.local_time_type_at(self.to_utc())
+    }
+}
+
+impl fmt::Display for ZonedDateTime {
+    /// Formats the date and time in RFC 3339 form followed by the name of the time zone,
+    /// like 2018-10-02T10:30:00+02:00[Europe/Berlin].
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]", self.odt, self.zone)
+    }
+}
+
+impl PartialEq for ZonedDateTime {
+    fn eq(&self, other: &ZonedDateTime) -> bool {
+        self.odt == other.odt
+    }
+}
+
+impl Eq for ZonedDateTime {}
+
+impl Hash for ZonedDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.odt.hash(state);
+    }
+}
+
+impl PartialOrd for ZonedDateTime {
+    fn partial_cmp(&self, other: &ZonedDateTime) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Ord for ZonedDateTime {
+    fn cmp(&self, other: &ZonedDateTime) -> Ordering {
+        self.odt.cmp(&other.odt)
+    }
+}
@@ -0,0 +1,89 @@
+extern crate date_time;
+
+mod common;
+
+use common::date_time;
+use date_time::clock::{Clock, FixedClock, SteppingClock, SystemClock};
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::month_tuple::MonthTuple;
+use date_time::time_tuple::{Duration, TimeTuple};
+use date_time::tz::TimeZone;
+use date_time::zoned_date_time::ZonedDateTime;
+
+#[test]
+fn test_fixed_clock() {
+    let clock = FixedClock::new(date_time(2018, 12, 31, 23, 59, 59));
+    assert_eq!(date_time(2018, 12, 31, 23, 59, 59), clock.now());
+    assert_eq!(
+        date_time(2018, 12, 31, 23, 59, 59),
+        DateTimeTuple::now_with_clock(&clock)
+    );
+    assert_eq!(
+        DateTuple::new(2018, 12, 31).unwrap(),
+        DateTuple::today_with_clock(&clock)
+    );
+    assert_eq!(
+        TimeTuple::new(23, 59, 59),
+        TimeTuple::now_with_clock(&clock)
+    );
+    assert_eq!(
+        MonthTuple::new(2018, 12).unwrap(),
+        MonthTuple::this_month_with_clock(&clock)
+    );
+
+    clock.advance(Duration::new(0, 0, 1));
+    assert_eq!(
+        DateTuple::new(2019, 1, 1).unwrap(),
+        DateTuple::today_with_clock(&clock)
+    );
+    clock.set(date_time(2000, 2, 29, 12, 0, 0));
+    assert_eq!(
+        MonthTuple::new(2000, 2).unwrap(),
+        MonthTuple::this_month_with_clock(&clock)
+    );
+    clock.set(DateTimeTuple::max_value());
+    clock.advance(Duration::new(1, 0, 0));
+    assert_eq!(DateTimeTuple::max_value(), clock.now());
+}
+
+#[test]
+fn test_stepping_clock() {
+    let clock = SteppingClock::new(date_time(2018, 10, 2, 8, 30, 0), Duration::new(0, 0, 30));
+    assert_eq!(Duration::new(0, 0, 30), clock.get_step());
+    assert_eq!(TimeTuple::new(8, 30, 0), TimeTuple::now_with_clock(&clock));
+    assert_eq!(TimeTuple::new(8, 30, 30), TimeTuple::now_with_clock(&clock));
+    let started = DateTimeTuple::now_with_clock(&clock);
+    let finished = DateTimeTuple::now_with_clock(&clock);
+    assert_eq!(Duration::new(0, 0, 30), finished - started);
+}
+
+#[test]
+fn test_local_time_with_clock() {
+    let clock = FixedClock::new(date_time(2018, 10, 2, 23, 30, 0));
+    let expected = ZonedDateTime::from_utc(clock.now(), &TimeZone::local()).unwrap();
+    assert_eq!(
+        expected.get_date_time(),
+        DateTimeTuple::now_local_with_clock(&clock)
+    );
+    assert_eq!(
+        expected.get_date(),
+        DateTuple::today_local_with_clock(&clock)
+    );
+}
+
+#[test]
+fn test_clocks_as_trait_objects() {
+    let clocks: Vec<Box<dyn Clock>> = vec![
+        Box::new(FixedClock::new(date_time(2018, 10, 2, 8, 30, 0))),
+        Box::new(SystemClock),
+    ];
+    assert_eq!(
+        DateTuple::new(2018, 10, 2).unwrap(),
+        DateTuple::today_with_clock(&clocks[0])
+    );
+    assert!(DateTimeTuple::now_with_clock(&*clocks[1]) > date_time(2018, 10, 2, 8, 30, 0));
+    let before = DateTimeTuple::now();
+    let now = SystemClock.now();
+    assert!(now >= before && now - before <= Duration::new(0, 0, 5));
+}
@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::time_tuple::TimeTuple;
+use date_time::Error;
+
+/// Builds a DateTimeTuple from a valid date and time.
+pub fn date_time(y: u16, mo: u8, d: u8, h: i32, mi: i32, s: i32) -> DateTimeTuple {
+    DateTimeTuple::new(DateTuple::new(y, mo, d).unwrap(), TimeTuple::new(h, mi, s))
+}
+
+/// Builds the `Error::ParseError` for a mismatch at a position in the input.
+pub fn parse_error(input: &str, position: usize, expected: &str) -> Error {
+    Error::ParseError {
+        input: input.to_string(),
+        position,
+        expected: expected.to_string(),
+    }
+}
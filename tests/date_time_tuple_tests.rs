@@ -1,9 +1,73 @@
 extern crate date_time;
 
+mod common;
+
+use common::date_time;
+use date_time::clock::{Clock, FixedClock};
 use date_time::date_time_tuple::DateTimeTuple;
 use date_time::date_tuple::DateTuple;
-use date_time::time_tuple::Duration;
 use date_time::time_tuple::TimeTuple;
+use date_time::time_tuple::{Duration, SignedDuration};
+use date_time::tz::TimeZone;
+use date_time::weekday::Weekday;
+use date_time::zoned_date_time::ZonedDateTime;
+use date_time::Error;
+use std::time::{self, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn test_now() {
+    let before = DateTimeTuple::now();
+    let local = DateTimeTuple::now_local();
+    let after = DateTimeTuple::now();
+    assert!(before <= after && after - before <= Duration::new(0, 0, 5));
+    let expected = ZonedDateTime::from_utc(before, &TimeZone::local()).unwrap();
+    assert!(local >= expected.get_date_time());
+    assert!(local - expected.get_date_time() <= Duration::new(0, 0, 5));
+}
+
+#[test]
+fn test_system_time_conversions() {
+    let seconds = |s: u64| time::Duration::from_secs(s);
+    let epoch = DateTimeTuple::from_rfc3339("1970-01-01T00:00:00Z").unwrap();
+    assert_eq!(Ok(epoch), DateTimeTuple::from_system_time(UNIX_EPOCH));
+    assert_eq!(Some(UNIX_EPOCH), epoch.to_system_time());
+
+    let launch = DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z").unwrap();
+    let system_time = UNIX_EPOCH + seconds(1_538_469_000);
+    assert_eq!(Some(system_time), launch.to_system_time());
+    assert_eq!(Ok(launch), DateTimeTuple::from_system_time(system_time));
+    assert_eq!(
+        Ok(launch),
+        DateTimeTuple::from_system_time(system_time + time::Duration::from_millis(999))
+    );
+
+    let landing = DateTimeTuple::from_rfc3339("1969-07-20T20:17:40Z").unwrap();
+    let system_time = UNIX_EPOCH - seconds(14_182_940);
+    assert_eq!(Some(system_time), landing.to_system_time());
+    assert_eq!(Ok(landing), DateTimeTuple::from_system_time(system_time));
+    assert_eq!(
+        DateTimeTuple::from_rfc3339("1969-12-31T23:59:59Z"),
+        DateTimeTuple::from_system_time(UNIX_EPOCH - time::Duration::from_millis(500))
+    );
+
+    for &dt in &[DateTimeTuple::min_value(), DateTimeTuple::max_value()] {
+        if let Some(system_time) = dt.to_system_time() {
+            assert_eq!(Ok(dt), DateTimeTuple::from_system_time(system_time));
+        }
+    }
+    if let Some(far_future) = UNIX_EPOCH.checked_add(seconds(300_000 * 31_557_600)) {
+        match DateTimeTuple::from_system_time(far_future) {
+            Err(Error::OutOfRange { .. }) => (),
+            r => panic!("Unexpected result: {:?}", r),
+        }
+    }
+
+    let before = SystemTime::now();
+    let now = DateTimeTuple::now();
+    let after = SystemTime::now();
+    assert!(DateTimeTuple::from_system_time(before).unwrap() <= now);
+    assert!(now <= DateTimeTuple::from_system_time(after).unwrap());
+}
 
 #[test]
 fn test_to_string() {
@@ -114,3 +178,450 @@ fn test_days_between() {
         )
     );
 }
+
+#[test]
+fn test_weekday() {
+    let tuple = DateTimeTuple::new(
+        DateTuple::new(2018, 10, 2).unwrap(),
+        TimeTuple::new(23, 30, 0),
+    );
+    assert_eq!(Weekday::Tuesday, tuple.weekday());
+}
+
+#[test]
+fn test_add_and_subtract_time_units() {
+    let mut tuple = date_time(2018, 10, 2, 23, 30, 0);
+    tuple.add_minutes(90);
+    assert_eq!(date_time(2018, 10, 3, 1, 0, 0), tuple);
+    tuple.subtract_seconds(3601);
+    assert_eq!(date_time(2018, 10, 2, 23, 59, 59), tuple);
+    tuple.add_hours(24 * 90 + 1);
+    assert_eq!(date_time(2019, 1, 1, 0, 59, 59), tuple);
+    tuple.add_seconds(1);
+    tuple.subtract_minutes(61);
+    assert_eq!(date_time(2018, 12, 31, 23, 59, 0), tuple);
+    tuple.subtract_hours(24);
+    assert_eq!(date_time(2018, 12, 30, 23, 59, 0), tuple);
+}
+
+#[test]
+fn test_add_and_subtract_date_units() {
+    let mut tuple = date_time(2000, 1, 31, 8, 30, 0);
+    tuple.add_days(30);
+    assert_eq!(date_time(2000, 3, 1, 8, 30, 0), tuple);
+    tuple.subtract_days(1);
+    assert_eq!(date_time(2000, 2, 29, 8, 30, 0), tuple);
+    tuple.add_years(1);
+    assert_eq!(date_time(2001, 2, 28, 8, 30, 0), tuple);
+    tuple.subtract_years(1);
+    tuple.add_months(1);
+    assert_eq!(date_time(2000, 3, 28, 8, 30, 0), tuple);
+    tuple.subtract_months(3);
+    assert_eq!(date_time(1999, 12, 28, 8, 30, 0), tuple);
+}
+
+#[test]
+fn test_arithmetic_saturates() {
+    let mut tuple = date_time(9999, 12, 31, 23, 0, 0);
+    tuple.add_hours(2);
+    assert_eq!(DateTimeTuple::max_value(), tuple);
+    tuple.add_seconds(u32::MAX);
+    assert_eq!(DateTimeTuple::max_value(), tuple);
+    let mut tuple = date_time(0, 1, 1, 1, 0, 0);
+    tuple.subtract_hours(2);
+    assert_eq!(DateTimeTuple::min_value(), tuple);
+}
+
+#[test]
+fn test_duration_operators() {
+    let tuple = date_time(2018, 10, 2, 23, 30, 0);
+    assert_eq!(
+        date_time(2018, 10, 3, 1, 0, 0),
+        tuple + Duration::new(1, 30, 0)
+    );
+    assert_eq!(
+        date_time(2018, 9, 30, 23, 30, 0),
+        tuple - Duration::new(48, 0, 0)
+    );
+    let mut tuple2 = tuple;
+    tuple2 += Duration::new(0, 30, 0);
+    assert_eq!(date_time(2018, 10, 3, 0, 0, 0), tuple2);
+    tuple2 -= Duration::new(0, 0, 1);
+    assert_eq!(date_time(2018, 10, 2, 23, 59, 59), tuple2);
+    assert_eq!(
+        DateTimeTuple::max_value(),
+        tuple + Duration::new(u32::MAX, 0, 0)
+    );
+    assert_eq!(
+        DateTimeTuple::min_value(),
+        tuple - Duration::new(u32::MAX, 0, 0)
+    );
+}
+
+#[test]
+fn test_subtract_date_times() {
+    let tuple1 = date_time(2018, 10, 2, 23, 30, 0);
+    let tuple2 = date_time(2018, 10, 3, 1, 0, 0);
+    assert_eq!(Duration::new(1, 30, 0), tuple2 - tuple1);
+    assert_eq!(Duration::new(1, 30, 0), tuple1 - tuple2);
+}
+
+#[test]
+fn test_signed_duration_since() {
+    let tuple1 = date_time(2018, 10, 2, 23, 30, 0);
+    let tuple2 = date_time(2018, 10, 3, 1, 0, 0);
+    assert_eq!(
+        SignedDuration::from_seconds(5400),
+        tuple2.signed_duration_since(tuple1)
+    );
+    assert_eq!(
+        SignedDuration::from_seconds(-5400),
+        tuple1.signed_duration_since(tuple2)
+    );
+    assert_eq!(
+        SignedDuration::from_seconds(-5400),
+        SignedDuration::between(tuple2, tuple1)
+    );
+}
+
+#[test]
+fn test_checked_and_saturating_arithmetic() {
+    let tuple = date_time(9999, 12, 31, 23, 0, 0);
+    assert_eq!(
+        Some(DateTimeTuple::max_value()),
+        tuple.checked_add_seconds(3599)
+    );
+    assert_eq!(None, tuple.checked_add_seconds(3600));
+    assert_eq!(None, tuple.checked_add_minutes(60));
+    assert_eq!(None, tuple.checked_add_hours(1));
+    assert_eq!(None, tuple.checked_add_days(1));
+    assert_eq!(None, tuple.checked_add_months(1));
+    assert_eq!(None, tuple.checked_add_years(1));
+    assert_eq!(
+        Some(date_time(9999, 12, 30, 23, 0, 0)),
+        tuple.checked_sub_days(1)
+    );
+    assert_eq!(
+        Some(date_time(9999, 12, 31, 21, 59, 0)),
+        tuple.checked_sub_minutes(61)
+    );
+    let tuple = date_time(0, 1, 1, 1, 0, 0);
+    assert_eq!(None, tuple.checked_sub_seconds(3601));
+    assert_eq!(None, tuple.checked_sub_hours(2));
+    assert_eq!(None, tuple.checked_sub_months(1));
+    assert_eq!(None, tuple.checked_sub_years(1));
+    assert_eq!(DateTimeTuple::min_value(), tuple.saturating_sub_hours(2));
+    assert_eq!(DateTimeTuple::min_value(), tuple.saturating_sub_years(1));
+    assert_eq!(
+        DateTimeTuple::max_value(),
+        tuple
+            .saturating_add_seconds(u32::MAX)
+            .saturating_add_hours(u32::MAX)
+    );
+    assert_eq!(
+        DateTimeTuple::max_value(),
+        tuple.saturating_add_days(u32::MAX)
+    );
+    assert_eq!(
+        date_time(0, 1, 1, 1, 30, 0),
+        tuple.saturating_add_minutes(30)
+    );
+    assert_eq!(
+        date_time(0, 1, 1, 0, 59, 0),
+        tuple.saturating_sub_minutes(1)
+    );
+    assert_eq!(
+        date_time(0, 1, 1, 0, 59, 59),
+        tuple.saturating_sub_seconds(1)
+    );
+    assert_eq!(date_time(0, 2, 1, 1, 0, 0), tuple.saturating_add_months(1));
+    assert_eq!(DateTimeTuple::min_value(), tuple.saturating_sub_months(1));
+    assert_eq!(DateTimeTuple::min_value(), tuple.saturating_sub_days(1));
+    assert_eq!(date_time(1, 1, 1, 1, 0, 0), tuple.saturating_add_years(1));
+    let tuple = date_time(9999, 12, 31, 8, 0, 0);
+    assert_eq!(DateTimeTuple::max_value(), tuple.saturating_add_days(5));
+    assert_eq!(DateTimeTuple::max_value(), tuple.saturating_add_months(1));
+    assert_eq!(DateTimeTuple::max_value(), tuple.saturating_add_years(1));
+    assert_eq!(
+        date_time(9998, 12, 31, 8, 0, 0),
+        tuple.saturating_sub_years(1)
+    );
+}
+
+#[test]
+fn test_to_rfc3339_and_iso8601_basic() {
+    let tuple = date_time(2018, 10, 2, 8, 30, 0);
+    assert_eq!("2018-10-02T08:30:00Z", tuple.to_rfc3339());
+    assert_eq!("20181002T083000Z", tuple.to_iso8601_basic());
+    assert_eq!(
+        "0001-01-01T00:00:00Z",
+        date_time(1, 1, 1, 0, 0, 0).to_rfc3339()
+    );
+}
+
+#[test]
+fn test_from_rfc3339() {
+    let expected = Ok(date_time(2018, 10, 2, 8, 30, 0));
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z")
+    );
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-02t08:30:00z")
+    );
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-02 08:30:00Z")
+    );
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-02T08:30:00.999999Z")
+    );
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-02T14:00:00+05:30")
+    );
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-01T23:30:00-09:00")
+    );
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_rfc3339("2018-10-02T08:30:00-00:00")
+    );
+    assert_eq!(
+        Ok(date_time(2016, 12, 31, 23, 59, 59)),
+        DateTimeTuple::from_rfc3339("2016-12-31T23:59:60Z")
+    );
+    let tuple = date_time(2024, 2, 29, 23, 59, 59);
+    assert_eq!(Ok(tuple), DateTimeTuple::from_rfc3339(&tuple.to_rfc3339()));
+}
+
+#[test]
+fn test_from_rfc3339_with_offset() {
+    assert_eq!(
+        Ok((date_time(2018, 10, 2, 14, 0, 0), 19800)),
+        DateTimeTuple::from_rfc3339_with_offset("2018-10-02T14:00:00+05:30")
+    );
+    assert_eq!(
+        Ok((date_time(2018, 10, 2, 14, 0, 0), -3600)),
+        DateTimeTuple::from_rfc3339_with_offset("2018-10-02T14:00:00-01:00")
+    );
+    assert_eq!(
+        Ok((date_time(2018, 10, 2, 14, 0, 0), 0)),
+        DateTimeTuple::from_rfc3339_with_offset("2018-10-02T14:00:00Z")
+    );
+}
+
+#[test]
+fn test_from_iso8601() {
+    let expected = Ok(date_time(2018, 10, 2, 8, 30, 0));
+    assert_eq!(expected, DateTimeTuple::from_iso8601("20181002T083000Z"));
+    assert_eq!(expected, DateTimeTuple::from_iso8601("20181002T083000,5Z"));
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_iso8601("20181002T140000+0530")
+    );
+    assert_eq!(expected, DateTimeTuple::from_iso8601("20181002T103000+02"));
+    assert_eq!(
+        expected,
+        DateTimeTuple::from_iso8601("2018-10-02T08:30:00Z")
+    );
+    let tuple = date_time(2024, 2, 29, 23, 59, 59);
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_iso8601(&tuple.to_iso8601_basic())
+    );
+}
+
+#[test]
+fn test_invalid_rfc3339() {
+    for s in &[
+        "2018-10-02T08:30:00",
+        "2018-10-02@08:30:00Z",
+        "2018-10-02T08:30Z",
+        "2018-10-02T24:00:00Z",
+        "2018-10-02T08:60:00Z",
+        "2018-10-02T08:30:61Z",
+        "2018-10-02T08:30:00+24:00",
+        "2018-10-02T08:30:00+0530",
+        "20181002T083000Z",
+    ] {
+        match DateTimeTuple::from_rfc3339(s) {
+            Err(Error::InvalidFormat { .. }) => (),
+            r => panic!("Unexpected result for {}: {:?}", s, r),
+        }
+    }
+    match DateTimeTuple::from_rfc3339("2018-02-30T08:30:00Z") {
+        Err(Error::InvalidDay { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match DateTimeTuple::from_iso8601("20181002T083000") {
+        Err(Error::InvalidFormat { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_rfc3339_out_of_range() {
+    assert_eq!(
+        Ok(DateTimeTuple::min_value()),
+        DateTimeTuple::from_rfc3339("0000-01-01T01:00:00+01:00")
+    );
+    match DateTimeTuple::from_rfc3339("0000-01-01T00:00:00+01:00") {
+        Err(Error::OutOfRange { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match DateTimeTuple::from_rfc3339("9999-12-31T23:00:00-01:00") {
+        Err(Error::OutOfRange { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_rfc2822() {
+    let tuple = date_time(2018, 10, 2, 8, 30, 0);
+    assert_eq!("Tue, 2 Oct 2018 08:30:00 +0000", tuple.to_rfc2822());
+    assert_eq!(Ok(tuple), DateTimeTuple::from_rfc2822(&tuple.to_rfc2822()));
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_rfc2822("Tue, 02 Oct 2018 10:30:00 +0200")
+    );
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_rfc2822("2 Oct 2018 04:30 EDT")
+    );
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_rfc2822("  tue ,  2  oct  18  08:30:00 GMT ")
+    );
+    assert_eq!(
+        Ok(date_time(1994, 1, 1, 0, 0, 0)),
+        DateTimeTuple::from_rfc2822("Sat, 1 Jan 94 00:00:00 +0000")
+    );
+    assert_eq!(
+        Ok(date_time(1999, 1, 1, 0, 0, 0)),
+        DateTimeTuple::from_rfc2822("Fri, 1 Jan 099 00:00:00 +0000")
+    );
+    assert_eq!(
+        Ok((tuple, -28800)),
+        DateTimeTuple::from_rfc2822_with_offset("Tue, 2 Oct 2018 08:30:00 PST")
+    );
+    assert_eq!(
+        Ok((tuple, 19800)),
+        DateTimeTuple::from_rfc2822_with_offset("Tue, 2 Oct 2018 08:30:00 +0530")
+    );
+}
+
+#[test]
+fn test_invalid_rfc2822() {
+    for s in &[
+        "Tue, 2 Oct 2018 08:30:00",
+        "Tue, 2 Okt 2018 08:30:00 +0000",
+        "Tue, 2 Oct 2018 08:30:00 XYZ",
+        "Tue, 2 Oct 2018 08:30:00 +0060",
+        "Tue, 2 Oct 2018 24:00:00 +0000",
+        "Tus, 2 Oct 2018 08:30:00 +0000",
+        "2018-10-02T08:30:00Z",
+    ] {
+        match DateTimeTuple::from_rfc2822(s) {
+            Err(Error::InvalidFormat { .. }) => (),
+            r => panic!("Unexpected result for {}: {:?}", s, r),
+        }
+    }
+    assert_eq!(
+        Err(Error::ParseError {
+            input: "Mon, 2 Oct 2018 08:30:00 +0000".to_string(),
+            position: 0,
+            expected: "Tuesday to agree with the rest of the input".to_string(),
+        }),
+        DateTimeTuple::from_rfc2822("Mon, 2 Oct 2018 08:30:00 +0000")
+    );
+}
+
+#[test]
+fn test_http_date() {
+    let tuple = date_time(1994, 11, 6, 8, 49, 37);
+    assert_eq!("Sun, 06 Nov 1994 08:49:37 GMT", tuple.to_http_date());
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT")
+    );
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_http_date("Sunday, 06-Nov-94 08:49:37 GMT")
+    );
+    assert_eq!(
+        Ok(tuple),
+        DateTimeTuple::from_http_date("Sun Nov  6 08:49:37 1994")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 12, 8, 30, 0)),
+        DateTimeTuple::from_http_date("Fri Oct 12 08:30:00 2018")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 8, 30, 0)),
+        DateTimeTuple::from_http_date("Tuesday, 02-Oct-18 08:30:00 GMT")
+    );
+}
+
+#[test]
+fn test_http_date_with_clock() {
+    let clock = FixedClock::new(date_time(2070, 1, 1, 0, 0, 0));
+    assert_eq!(
+        Ok(date_time(2094, 11, 4, 8, 49, 37)),
+        DateTimeTuple::from_http_date_with_clock("Thursday, 04-Nov-94 08:49:37 GMT", &clock)
+    );
+    assert_eq!(
+        Ok(date_time(2019, 10, 2, 8, 30, 0)),
+        DateTimeTuple::from_http_date_with_clock("Wednesday, 02-Oct-19 08:30:00 GMT", &clock)
+    );
+    clock.set(date_time(2018, 10, 2, 8, 30, 0));
+    assert_eq!(
+        Ok(date_time(2068, 10, 2, 8, 30, 0)),
+        DateTimeTuple::from_http_date_with_clock("Tuesday, 02-Oct-68 08:30:00 GMT", &clock)
+    );
+    assert_eq!(
+        Ok(date_time(1969, 10, 2, 8, 30, 0)),
+        DateTimeTuple::from_http_date_with_clock("Thursday, 02-Oct-69 08:30:00 GMT", &clock)
+    );
+
+    struct UnreadableClock;
+    impl Clock for UnreadableClock {
+        fn now(&self) -> DateTimeTuple {
+            panic!("The clock should not be read for four-digit years");
+        }
+    }
+    assert_eq!(
+        Ok(date_time(1994, 11, 6, 8, 49, 37)),
+        DateTimeTuple::from_http_date_with_clock("Sun, 06 Nov 1994 08:49:37 GMT", &UnreadableClock)
+    );
+    assert_eq!(
+        Ok(date_time(1994, 11, 6, 8, 49, 37)),
+        DateTimeTuple::from_http_date_with_clock("Sun Nov  6 08:49:37 1994", &UnreadableClock)
+    );
+}
+
+#[test]
+fn test_invalid_http_date() {
+    for s in &[
+        "Sun, 6 Nov 1994 08:49:37 GMT",
+        "Sun, 06 Nov 1994 08:49:37 +0000",
+        "Sun, 06-Nov-94 08:49:37 GMT",
+        "Sun Nov 06 08:49:37 1994 GMT",
+    ] {
+        match DateTimeTuple::from_http_date(s) {
+            Err(Error::InvalidFormat { .. }) => (),
+            r => panic!("Unexpected result for {}: {:?}", s, r),
+        }
+    }
+    assert_eq!(
+        Err(Error::ParseError {
+            input: "Monday, 06-Nov-94 08:49:37 GMT".to_string(),
+            position: 0,
+            expected: "Sunday to agree with the rest of the input".to_string(),
+        }),
+        DateTimeTuple::from_http_date("Monday, 06-Nov-94 08:49:37 GMT")
+    );
+}
@@ -1,6 +1,9 @@
+#![allow(clippy::nonminimal_bool)]
+
 extern crate date_time;
 
 use date_time::date_tuple::{Date, DateTuple};
+use date_time::weekday::Weekday;
 
 #[test]
 fn test_year_too_large() {
@@ -17,6 +20,11 @@ fn test_today_does_not_panic() {
     DateTuple::today();
 }
 
+#[test]
+fn test_today_local_does_not_panic() {
+    DateTuple::today_local();
+}
+
 #[test]
 fn test_getters() {
     let date = DateTuple::new(2000, 5, 10).unwrap();
@@ -168,3 +176,179 @@ fn test_from_days() {
     assert_eq!(feb_29_2000, DateTuple::from_days(730_545).unwrap());
     assert!(DateTuple::from_days(0).is_err());
 }
+
+#[test]
+fn test_days_bounds() {
+    assert_eq!(1, DateTuple::min_value().to_days());
+    assert_eq!(3_652_425, DateTuple::max_value().to_days());
+    assert_eq!(
+        DateTuple::max_value(),
+        DateTuple::from_days(3_652_425).unwrap()
+    );
+    assert!(DateTuple::from_days(3_652_426).is_err());
+    assert!(DateTuple::from_days(u32::MAX).is_err());
+}
+
+#[test]
+fn test_days_round_trip_for_every_date() {
+    let mut date = DateTuple::min_value();
+    let mut days = 1;
+    loop {
+        assert_eq!(days, date.to_days());
+        assert_eq!(date, DateTuple::from_days(days).unwrap());
+        if date == DateTuple::max_value() {
+            break;
+        }
+        date = date.next_date();
+        days += 1;
+    }
+}
+
+#[test]
+fn test_add_and_subtract_many_days() {
+    let mut tuple1 = DateTuple::new(2000, 2, 28).unwrap();
+    tuple1.add_days(366);
+    assert_eq!(DateTuple::new(2001, 2, 28).unwrap(), tuple1);
+    tuple1.subtract_days(366);
+    assert_eq!(DateTuple::new(2000, 2, 28).unwrap(), tuple1);
+    tuple1.add_days(3_000_000);
+    assert_eq!(DateTuple::max_value(), tuple1);
+    tuple1.add_days(u32::MAX);
+    assert_eq!(DateTuple::max_value(), tuple1);
+    tuple1.subtract_days(u32::MAX);
+    assert_eq!(DateTuple::min_value(), tuple1);
+}
+
+#[test]
+fn test_add_many_months_clamps_day() {
+    let mut tuple = DateTuple::new(2000, 1, 31).unwrap();
+    tuple.add_months(25);
+    assert_eq!(DateTuple::new(2002, 2, 28).unwrap(), tuple);
+    tuple.add_months(200_000);
+    assert_eq!(DateTuple::new(9999, 12, 28).unwrap(), tuple);
+}
+
+#[test]
+fn test_weekday() {
+    assert_eq!(Weekday::Saturday, DateTuple::min_value().weekday());
+    assert_eq!(Weekday::Friday, DateTuple::max_value().weekday());
+    assert_eq!(
+        Weekday::Tuesday,
+        DateTuple::new(2018, 10, 2).unwrap().weekday()
+    );
+    assert_eq!(
+        Weekday::Thursday,
+        DateTuple::new(1970, 1, 1).unwrap().weekday()
+    );
+}
+
+#[test]
+fn test_week_date_strings() {
+    let tuple = DateTuple::new(2024, 2, 14).unwrap();
+    assert_eq!(
+        String::from("2024-W07-3"),
+        tuple.to_week_date_string().unwrap()
+    );
+    assert_eq!(tuple, str::parse("2024-W07-3").unwrap());
+    assert_eq!(tuple, str::parse("2024W073").unwrap());
+    assert_eq!(
+        DateTuple::new(2021, 1, 3).unwrap(),
+        str::parse("2020-W53-7").unwrap()
+    );
+    assert!(DateTuple::min_value().to_week_date_string().is_err());
+    assert!(str::parse::<DateTuple>("2021-W53-1").is_err());
+    assert!(str::parse::<DateTuple>("2024-W07-8").is_err());
+    assert!(str::parse::<DateTuple>("2024-W073").is_err());
+    assert!(str::parse::<DateTuple>("9999-W52-6").is_err());
+}
+
+#[test]
+fn test_day_of_year() {
+    assert_eq!(1, DateTuple::new(2019, 1, 1).unwrap().day_of_year());
+    assert_eq!(45, DateTuple::new(2019, 2, 14).unwrap().day_of_year());
+    assert_eq!(365, DateTuple::new(2019, 12, 31).unwrap().day_of_year());
+    assert_eq!(366, DateTuple::new(2020, 12, 31).unwrap().day_of_year());
+}
+
+#[test]
+fn test_from_ordinal() {
+    assert_eq!(
+        DateTuple::new(2019, 2, 14).unwrap(),
+        DateTuple::from_ordinal(2019, 45).unwrap()
+    );
+    assert_eq!(
+        DateTuple::new(2020, 12, 31).unwrap(),
+        DateTuple::from_ordinal(2020, 366).unwrap()
+    );
+    assert!(DateTuple::from_ordinal(2019, 366).is_err());
+    assert!(DateTuple::from_ordinal(2019, 0).is_err());
+    assert!(DateTuple::from_ordinal(10000, 1).is_err());
+}
+
+#[test]
+fn test_ordinal_date_strings() {
+    let tuple = DateTuple::new(2019, 2, 14).unwrap();
+    assert_eq!(String::from("2019-045"), tuple.to_ordinal_string());
+    assert_eq!(tuple, str::parse("2019-045").unwrap());
+    assert_eq!(tuple, str::parse("2019045").unwrap());
+    assert!(str::parse::<DateTuple>("2019-366").is_err());
+    assert!(str::parse::<DateTuple>("2019-45").is_err());
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let tuple = DateTuple::new(2000, 1, 31).unwrap();
+    assert_eq!(
+        Some(DateTuple::new(2000, 2, 1).unwrap()),
+        tuple.checked_add_days(1)
+    );
+    assert_eq!(
+        Some(DateTuple::new(2000, 2, 29).unwrap()),
+        tuple.checked_add_months(1)
+    );
+    assert_eq!(
+        Some(DateTuple::new(1999, 1, 31).unwrap()),
+        tuple.checked_sub_years(1)
+    );
+    assert_eq!(None, tuple.checked_add_days(3_000_000));
+    assert_eq!(None, tuple.checked_sub_days(800_000));
+    assert_eq!(None, tuple.checked_add_months(100_000));
+    assert_eq!(None, tuple.checked_sub_months(24_001));
+    assert_eq!(None, tuple.checked_add_years(8000));
+    assert_eq!(None, tuple.checked_sub_years(2001));
+    assert_eq!(
+        Some(DateTuple::max_value()),
+        DateTuple::new(9998, 12, 31).unwrap().checked_add_years(1)
+    );
+    assert_eq!(
+        Some(DateTuple::new(2001, 2, 28).unwrap()),
+        DateTuple::new(2000, 2, 29).unwrap().checked_add_years(1)
+    );
+}
+
+#[test]
+fn test_saturating_arithmetic() {
+    let tuple = DateTuple::new(2000, 1, 31).unwrap();
+    assert_eq!(DateTuple::max_value(), tuple.saturating_add_days(u32::MAX));
+    assert_eq!(DateTuple::min_value(), tuple.saturating_sub_days(u32::MAX));
+    assert_eq!(
+        DateTuple::new(9999, 12, 31).unwrap(),
+        tuple.saturating_add_months(u32::MAX)
+    );
+    assert_eq!(
+        DateTuple::new(0, 1, 31).unwrap(),
+        tuple.saturating_sub_months(u32::MAX)
+    );
+    assert_eq!(
+        DateTuple::new(9999, 1, 31).unwrap(),
+        tuple.saturating_add_years(u16::MAX)
+    );
+    assert_eq!(
+        DateTuple::new(0, 1, 31).unwrap(),
+        tuple.saturating_sub_years(u16::MAX)
+    );
+    assert_eq!(
+        DateTuple::new(2000, 2, 29).unwrap(),
+        tuple.saturating_add_months(1)
+    );
+}
@@ -0,0 +1,172 @@
+extern crate date_time;
+
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::error::TypeName;
+use date_time::month_tuple::MonthTuple;
+use date_time::quarter_tuple::QuarterTuple;
+use date_time::time_tuple::{Duration, TimeTuple};
+use date_time::weekday::Weekday;
+use date_time::Error;
+use std::str::FromStr;
+
+#[test]
+fn test_invalid_components() {
+    match DateTuple::new(2000, 13, 1) {
+        Err(Error::InvalidMonth { type_name, .. }) => assert_eq!(TypeName::DateTuple, type_name),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match DateTuple::new(2001, 2, 29) {
+        Err(Error::InvalidDay { type_name, .. }) => assert_eq!(TypeName::DateTuple, type_name),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match MonthTuple::new(10000, 1) {
+        Err(Error::InvalidYear { type_name, .. }) => assert_eq!(TypeName::MonthTuple, type_name),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert_eq!(
+        Err(Error::InvalidWeekday {
+            value: 8,
+            from_sunday: false
+        }),
+        Weekday::from_number_from_monday(8)
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        Err(Error::InvalidFormat {
+            type_name: TypeName::DateTuple,
+            input: String::from("2000/01/01"),
+            expected: "2018-11-02",
+        }),
+        DateTuple::from_str("2000/01/01")
+    );
+    match TimeTuple::from_str("8:30") {
+        Err(Error::InvalidFormat { type_name, .. }) => assert_eq!(TypeName::TimeTuple, type_name),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match Duration::from_str("8:3:00") {
+        Err(Error::InvalidFormat { type_name, .. }) => assert_eq!(TypeName::Duration, type_name),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match DateTimeTuple::from_str("2000-01-01T00:00:00") {
+        Err(Error::InvalidFormat { type_name, .. }) => {
+            assert_eq!(TypeName::DateTimeTuple, type_name)
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_parsing_wraps_component_errors() {
+    match DateTuple::from_str("2000-13-01") {
+        Err(Error::InvalidParsedValue { type_name, error }) => {
+            assert_eq!(TypeName::DateTuple, type_name);
+            match *error {
+                Error::InvalidMonth { .. } => (),
+                e => panic!("Unexpected error: {:?}", e),
+            }
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match DateTimeTuple::from_str("2000-02-30@08:00:00") {
+        Err(Error::InvalidParsedValue { type_name, .. }) => {
+            assert_eq!(TypeName::DateTimeTuple, type_name)
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    let e = MonthTuple::from_str("2000-13").unwrap_err();
+    let source = std::error::Error::source(&e).unwrap();
+    assert!(source
+        .to_string()
+        .starts_with("Invalid month in MonthTuple"));
+}
+
+#[test]
+fn test_out_of_range() {
+    match DateTuple::from_days(0) {
+        Err(Error::OutOfRange { type_name, .. }) => assert_eq!(TypeName::DateTuple, type_name),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(
+        "Invalid year in DateTuple DateTuple { y: 10000, m: 1, d: 1 }: year must be <= 9999.",
+        DateTuple::new(10000, 1, 1).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid month in DateTuple: DateTuple { y: 2000, m: 13, d: 1 }\nMonth must be between 1 and 12; Note that months are ONE-BASED since version 2.0.0.",
+        DateTuple::new(2000, 13, 1).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid date in DateTuple: DateTuple { y: 2000, m: 6, d: 31 }",
+        DateTuple::new(2000, 6, 31).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid year in MonthTuple: MonthTuple { y: 10000, m: 1 }\nYear must be <= 9999.",
+        MonthTuple::new(10000, 1).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid month in MonthTuple: MonthTuple { y: 2000, m: 13 }\nMonth must be between 1 and 12; Note that months are ONE-BASED since version 2.0.0.",
+        MonthTuple::new(2000, 13).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_display_of_parsing_errors() {
+    assert_eq!(
+        "Invalid str formatting of DateTuple: 2000/01/01\nExpects a string formatted like 2018-11-02.",
+        DateTuple::from_str("2000/01/01").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid date passed to from_str: Invalid date in DateTuple: DateTuple { y: 2000, m: 2, d: 30 }",
+        DateTuple::from_str("2000-02-30").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid str formatting of MonthTuple: 2000/01\nExpects a string formatted like 2018-11",
+        MonthTuple::from_str("2000/01").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid month passed to from_str: Invalid month in MonthTuple: MonthTuple { y: 2000, m: 13 }\nMonth must be between 1 and 12; Note that months are ONE-BASED since version 2.0.0.",
+        MonthTuple::from_str("200013").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid str formatting of TimeTuple: 8:30\nExpects a string formatted like 08:30:05",
+        TimeTuple::from_str("8:30").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid str formatting of Duration: 8:3:00\nExpects a string formatted like 8:30:05",
+        Duration::from_str("8:3:00").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid str formatting of DateTimeTuple: 2000-01-01T00:00:00\nExpects a string formatted like 2018-11-02@08:30:00",
+        DateTimeTuple::from_str("2000-01-01T00:00:00").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Invalid date passed to from_str: Invalid date passed to from_str: Invalid date in DateTuple: DateTuple { y: 2000, m: 2, d: 30 }",
+        DateTimeTuple::from_str("2000-02-30@08:00:00").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_type_names() {
+    assert_eq!("DateTuple", TypeName::DateTuple.to_string());
+    assert_eq!(
+        "Invalid quarter passed to from_str: Invalid quarter in QuarterTuple: QuarterTuple { y: 2018, q: 5 }\nQuarter must be between 1 and 4.",
+        Error::InvalidParsedValue {
+            type_name: TypeName::QuarterTuple,
+            error: Box::new(QuarterTuple::new(2018, 5).unwrap_err()),
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_is_std_error() {
+    let e: Box<dyn std::error::Error> = Box::new(DateTuple::new(2000, 13, 1).unwrap_err());
+    assert!(e.to_string().starts_with("Invalid month"));
+}
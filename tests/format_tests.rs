@@ -0,0 +1,173 @@
+extern crate date_time;
+
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::error::TypeName;
+use date_time::format::Pattern;
+use date_time::month_tuple::MonthTuple;
+use date_time::time_tuple::{Duration, TimeTuple};
+use date_time::Error;
+use std::str::FromStr;
+
+fn date_time() -> DateTimeTuple {
+    DateTimeTuple::new(
+        DateTuple::new(2018, 10, 2).unwrap(),
+        TimeTuple::new(20, 30, 5),
+    )
+}
+
+#[test]
+fn test_date_directives() {
+    let date = DateTuple::new(2018, 10, 2).unwrap();
+    assert_eq!("02/10/2018", date.format("%d/%m/%Y").unwrap());
+    assert_eq!("2 October 18", date.format("%-d %B %y").unwrap());
+    assert_eq!("Tue, 02 Oct", date.format("%a, %d %b").unwrap());
+    assert_eq!("Tuesday 2 2", date.format("%A %u %w").unwrap());
+    assert_eq!("2018-275 Q4", date.format("%Y-%j Q%q").unwrap());
+    assert_eq!("2018-W40", date.format("%G-W%V").unwrap());
+    assert_eq!("2018-10-02", date.format("%F").unwrap());
+    assert_eq!(
+        "0005-01-01",
+        DateTuple::new(5, 1, 1).unwrap().format("%F").unwrap()
+    );
+    assert_eq!(
+        "2009-W53 Sunday 0",
+        DateTuple::new(2010, 1, 3)
+            .unwrap()
+            .format("%G-W%V %A %w")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_time_directives() {
+    assert_eq!(
+        "8:30 PM",
+        TimeTuple::new(20, 30, 5).format("%-I:%M %p").unwrap()
+    );
+    assert_eq!(
+        "12:00 AM",
+        TimeTuple::new(0, 0, 0).format("%I:%M %p").unwrap()
+    );
+    assert_eq!(
+        "12:00 PM",
+        TimeTuple::new(12, 0, 0).format("%I:%M %p").unwrap()
+    );
+    assert_eq!("08:05:09", TimeTuple::new(8, 5, 9).format("%T").unwrap());
+    assert_eq!(
+        "8h5m9s",
+        TimeTuple::new(8, 5, 9).format("%-Hh%-Mm%-Ss").unwrap()
+    );
+}
+
+#[test]
+fn test_date_time_and_month_directives() {
+    assert_eq!(
+        "Tue 2 Oct 2018 20:30",
+        date_time().format("%a %-d %b %Y %H:%M").unwrap()
+    );
+    assert_eq!("2018-10-02T20:30:05", date_time().format("%FT%T").unwrap());
+    assert_eq!(
+        "October 2018",
+        MonthTuple::new(2018, 10).unwrap().format("%B %Y").unwrap()
+    );
+}
+
+#[test]
+fn test_duration_directives() {
+    assert_eq!(
+        "150h 05m",
+        Duration::new(150, 5, 0).format("%-Hh %Mm").unwrap()
+    );
+    assert_eq!("03:00:07", Duration::new(3, 0, 7).format("%T").unwrap());
+}
+
+#[test]
+fn test_literals() {
+    let date = DateTuple::new(2018, 10, 2).unwrap();
+    assert_eq!("100% on 2018", date.format("100%% on %Y").unwrap());
+    assert_eq!("no directives", date.format("no directives").unwrap());
+    assert_eq!("", date.format("").unwrap());
+    assert_eq!("é 02", date.format("é %d").unwrap());
+}
+
+#[test]
+fn test_compiled_pattern() {
+    let pattern = Pattern::new("%d.%m.%Y").unwrap();
+    assert_eq!(Ok(pattern.clone()), Pattern::from_str("%d.%m.%Y"));
+    assert_eq!(
+        "02.10.2018",
+        DateTuple::new(2018, 10, 2)
+            .unwrap()
+            .format_with(&pattern)
+            .unwrap()
+    );
+    assert_eq!("02.10.2018", date_time().format_with(&pattern).unwrap());
+}
+
+#[test]
+fn test_invalid_directives() {
+    let date = DateTuple::new(2018, 10, 2).unwrap();
+    assert_eq!(
+        Err(Error::InvalidDirective {
+            pattern: "%Y-%K".to_string(),
+            position: 3
+        }),
+        date.format("%Y-%K")
+    );
+    assert_eq!(
+        Err(Error::InvalidDirective {
+            pattern: "%Y %".to_string(),
+            position: 3
+        }),
+        date.format("%Y %")
+    );
+    assert_eq!(
+        Err(Error::InvalidDirective {
+            pattern: "%-".to_string(),
+            position: 0
+        }),
+        date.format("%-")
+    );
+    assert!(date.format("%-B").is_err());
+    assert!(date.format("%-%").is_err());
+}
+
+#[test]
+fn test_unsupported_directives() {
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::MonthTuple,
+            directive: 'd'
+        }),
+        MonthTuple::new(2018, 10).unwrap().format("%d")
+    );
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::TimeTuple,
+            directive: 'Y'
+        }),
+        TimeTuple::new(1, 0, 0).format("%Y")
+    );
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::DateTuple,
+            directive: 'H'
+        }),
+        DateTuple::new(2018, 10, 2).unwrap().format("%F %T")
+    );
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::Duration,
+            directive: 'p'
+        }),
+        Duration::new(1, 0, 0).format("%p")
+    );
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::DateTuple,
+            directive: 'V'
+        }),
+        DateTuple::new(0, 1, 1).unwrap().format("%V")
+    );
+}
@@ -0,0 +1,183 @@
+extern crate date_time;
+
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::format::Pattern;
+use date_time::locale::{DateOrder, Locale};
+use date_time::month_tuple::MonthTuple;
+use date_time::time_tuple::TimeTuple;
+use date_time::Error;
+use std::str::FromStr;
+
+fn date() -> DateTuple {
+    DateTuple::new(2018, 10, 2).unwrap()
+}
+
+fn date_time() -> DateTimeTuple {
+    DateTimeTuple::new(date(), TimeTuple::new(20, 30, 0))
+}
+
+fn pattern(pattern: &str, locale: Locale) -> Pattern {
+    Pattern::new(pattern).unwrap().with_locale(locale)
+}
+
+#[test]
+fn test_find_locale() {
+    assert_eq!(Some(Locale::FR), Locale::find("fr"));
+    assert_eq!(Some(Locale::DE), Locale::find("DE"));
+    assert_eq!(Some(Locale::EN_US), Locale::find("en-us"));
+    assert_eq!(Some(Locale::ES), Locale::find("es_MX"));
+    assert_eq!(Some(Locale::EN), Locale::find("en-GB"));
+    assert_eq!(None, Locale::find("xx"));
+    assert_eq!(Ok(Locale::JA), Locale::from_str("ja-JP"));
+    assert_eq!(
+        Err(Error::UnknownLocale {
+            code: "xx".to_string()
+        }),
+        Locale::from_str("xx")
+    );
+    assert_eq!(Locale::EN, Locale::default());
+}
+
+#[test]
+fn test_readable_strings() {
+    let month = MonthTuple::new(2018, 10).unwrap();
+    assert_eq!("Oct 2018", month.to_readable_string());
+    assert_eq!("oct. 2018", month.to_readable_string_in(&Locale::FR));
+    assert_eq!("2018 10月", month.to_readable_string_in(&Locale::JA));
+    assert_eq!("2 Oct 2018", date().to_readable_string());
+    assert_eq!("Oct 2, 2018", date().to_readable_string_in(&Locale::EN_US));
+    assert_eq!("2 Okt. 2018", date().to_readable_string_in(&Locale::DE));
+    assert_eq!(
+        "2 oct 2018 20:30:00",
+        date_time().to_readable_string_in(&Locale::ES)
+    );
+}
+
+#[test]
+fn test_format_with_locale() {
+    assert_eq!(
+        "mardi 2 octobre 2018",
+        date()
+            .format_with(&pattern("%A %-d %B %Y", Locale::FR))
+            .unwrap()
+    );
+    assert_eq!(
+        "Di., 02. Okt. 2018",
+        date()
+            .format_with(&pattern("%a, %d. %b %Y", Locale::DE))
+            .unwrap()
+    );
+    assert_eq!(
+        "2018年10月2日(火) 午後8時30分",
+        date_time()
+            .format_with(&pattern("%Y年%B%-d日(%a) %p%-I時%M分", Locale::JA))
+            .unwrap()
+    );
+    assert_eq!(
+        "8:30 p. m.",
+        TimeTuple::new(20, 30, 0)
+            .format_with(&pattern("%-I:%M %p", Locale::ES))
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_parse_with_locale() {
+    assert_eq!(
+        Ok(date()),
+        DateTuple::parse_with("MARDI 2 octobre 2018", &pattern("%A %-d %B %Y", Locale::FR))
+    );
+    assert_eq!(
+        Ok(DateTuple::new(2019, 3, 2).unwrap()),
+        DateTuple::parse_with("2. März 2019", &pattern("%-d. %B %Y", Locale::DE))
+    );
+    assert_eq!(
+        Ok(date()),
+        DateTuple::parse_with("2018年10月2日", &pattern("%Y年%B%-d日", Locale::JA))
+    );
+    assert_eq!(
+        Ok(TimeTuple::new(20, 30, 0)),
+        TimeTuple::parse_with("8:30 p. m.", &pattern("%-I:%M %p", Locale::ES))
+    );
+    assert_eq!(
+        Err(Error::ParseError {
+            input: "lundi 2 octobre 2018".to_string(),
+            position: 0,
+            expected: "mardi to agree with the rest of the input".to_string(),
+        }),
+        DateTuple::parse_with("lundi 2 octobre 2018", &pattern("%A %-d %B %Y", Locale::FR))
+    );
+}
+
+#[test]
+fn test_register_locale() {
+    let dutch = Locale::new(
+        "nl",
+        [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        ["a.m.", "p.m."],
+        DateOrder::DayMonthYear,
+    );
+    assert_eq!(None, Locale::find("nl"));
+    dutch.clone().register();
+    assert_eq!(Some(dutch.clone()), Locale::find("nl-BE"));
+    assert_eq!("nl", dutch.get_code());
+    assert_eq!(
+        "dinsdag 2 oktober 2018",
+        date()
+            .format_with(&pattern("%A %-d %B %Y", Locale::find("nl").unwrap()))
+            .unwrap()
+    );
+    assert_eq!("2 okt 2018", date().to_readable_string_in(&dutch));
+}
+
+#[test]
+fn test_locale_from_runtime_data() {
+    let months: [String; 12] = std::array::from_fn(|i| format!("M{}", i + 1));
+    let weekdays: [String; 7] = std::array::from_fn(|i| format!("D{}", i + 1));
+    Locale::new(
+        String::from("x-test"),
+        months.clone(),
+        months,
+        weekdays.clone(),
+        weekdays,
+        [String::from("am"), String::from("pm")],
+        DateOrder::YearMonthDay,
+    )
+    .register();
+    let locale = Locale::find("X-TEST").unwrap();
+    assert_eq!("x-test", locale.get_code());
+    assert_eq!(
+        "D2 2 M10 2018 pm",
+        date_time()
+            .format_with(&pattern("%A %-d %B %Y %p", locale.clone()))
+            .unwrap()
+    );
+    assert_eq!("2018 M10 2", date().to_readable_string_in(&locale));
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::nonminimal_bool)]
+
 extern crate date_time;
 
 use date_time::month_tuple::MonthTuple;
@@ -100,3 +102,58 @@ fn test_subtract_years() {
     tuple2.subtract_years(2);
     assert_eq!(0, tuple2.get_year());
 }
+
+#[test]
+fn test_add_and_subtract_many_months() {
+    let mut tuple1 = MonthTuple::new(2000, 6).unwrap();
+    tuple1.add_months(100_000);
+    assert_eq!(MonthTuple::new(9999, 12).unwrap(), tuple1);
+    let mut tuple2 = MonthTuple::new(2000, 6).unwrap();
+    tuple2.add_months(31);
+    assert_eq!(MonthTuple::new(2003, 1).unwrap(), tuple2);
+    tuple2.subtract_months(31);
+    assert_eq!(MonthTuple::new(2000, 6).unwrap(), tuple2);
+    tuple2.subtract_months(u32::MAX);
+    assert_eq!(MonthTuple::new(0, 1).unwrap(), tuple2);
+}
+
+#[test]
+fn test_checked_and_saturating_arithmetic() {
+    let tuple = MonthTuple::new(2000, 6).unwrap();
+    assert_eq!(
+        Some(MonthTuple::new(2001, 1).unwrap()),
+        tuple.checked_add_months(7)
+    );
+    assert_eq!(
+        Some(MonthTuple::new(0, 1).unwrap()),
+        tuple.checked_sub_months(24_005)
+    );
+    assert_eq!(None, tuple.checked_sub_months(24_006));
+    assert_eq!(
+        Some(MonthTuple::new(9999, 12).unwrap()),
+        tuple.checked_add_months(95_994)
+    );
+    assert_eq!(None, tuple.checked_add_months(95_995));
+    assert_eq!(None, tuple.checked_add_years(8000));
+    assert_eq!(None, tuple.checked_sub_years(2001));
+    assert_eq!(
+        Some(MonthTuple::new(1990, 6).unwrap()),
+        tuple.checked_sub_years(10)
+    );
+    assert_eq!(
+        MonthTuple::new(9999, 12).unwrap(),
+        tuple.saturating_add_months(u32::MAX)
+    );
+    assert_eq!(
+        MonthTuple::new(0, 1).unwrap(),
+        tuple.saturating_sub_months(u32::MAX)
+    );
+    assert_eq!(
+        MonthTuple::new(9999, 6).unwrap(),
+        tuple.saturating_add_years(u16::MAX)
+    );
+    assert_eq!(
+        MonthTuple::new(0, 6).unwrap(),
+        tuple.saturating_sub_years(u16::MAX)
+    );
+}
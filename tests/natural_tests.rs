@@ -0,0 +1,184 @@
+extern crate date_time;
+
+mod common;
+
+use common::{date_time, parse_error};
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::Error;
+
+/// Tuesday 2 Oct 2018 at 08:30.
+fn reference() -> DateTimeTuple {
+    date_time(2018, 10, 2, 8, 30, 0)
+}
+
+fn parse(input: &str) -> Result<DateTimeTuple, Error> {
+    DateTimeTuple::parse_natural(input, reference())
+}
+
+fn parse_date(input: &str) -> Result<DateTuple, Error> {
+    DateTuple::parse_natural(input, reference())
+}
+
+#[test]
+fn test_parse_days_and_weekdays() {
+    assert_eq!(Ok(reference()), parse("now"));
+    assert_eq!(Ok(date_time(2018, 10, 3, 8, 30, 0)), parse("Tomorrow"));
+    assert_eq!(
+        Ok(date_time(2018, 10, 4, 8, 30, 0)),
+        parse("the day after tomorrow")
+    );
+    assert_eq!(Ok(date_time(2018, 10, 5, 8, 30, 0)), parse("next Friday"));
+    assert_eq!(Ok(date_time(2018, 10, 5, 8, 30, 0)), parse("fri"));
+    assert_eq!(Ok(date_time(2018, 10, 2, 8, 30, 0)), parse("this Tuesday"));
+    assert_eq!(Ok(date_time(2018, 10, 9, 8, 30, 0)), parse("next Tuesday"));
+    assert_eq!(Ok(date_time(2018, 9, 25, 8, 30, 0)), parse("last Tuesday"));
+    assert_eq!(Ok(date_time(2018, 9, 28, 8, 30, 0)), parse("last friday"));
+}
+
+#[test]
+fn test_parse_offsets() {
+    assert_eq!(Ok(date_time(2018, 10, 16, 8, 30, 0)), parse("in 2 weeks"));
+    assert_eq!(Ok(date_time(2018, 9, 29, 8, 30, 0)), parse("3 days ago"));
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 9, 30, 0)),
+        parse("an hour from now")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 8, 40, 0)),
+        parse("in ten minutes")
+    );
+    assert_eq!(Ok(date_time(2018, 9, 2, 8, 30, 0)), parse("a month ago"));
+    assert_eq!(
+        Ok(date_time(2018, 10, 4, 12, 0, 0)),
+        parse("in 2 days at noon")
+    );
+    assert_eq!(Ok(date_time(2018, 11, 2, 8, 30, 0)), parse("next month"));
+    assert_eq!(Ok(date_time(2017, 10, 2, 8, 30, 0)), parse("last year"));
+}
+
+#[test]
+fn test_parse_periods_and_dates() {
+    assert_eq!(Ok(date_time(2018, 10, 31, 8, 30, 0)), parse("end of month"));
+    assert_eq!(
+        Ok(date_time(2018, 11, 1, 8, 30, 0)),
+        parse("start of next month")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 7, 8, 30, 0)),
+        parse("end of the week")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 1, 1, 8, 30, 0)),
+        parse("first day of the year")
+    );
+    assert_eq!(
+        Ok(date_time(2019, 2, 28, 8, 30, 0)),
+        parse("last day of February")
+    );
+    assert_eq!(
+        Ok(date_time(2020, 2, 29, 8, 30, 0)),
+        parse("last day of Feb 2020")
+    );
+    assert_eq!(Ok(date_time(2019, 3, 5, 8, 30, 0)), parse("March 5th"));
+    assert_eq!(Ok(date_time(2018, 10, 2, 8, 30, 0)), parse("Oct 2"));
+    assert_eq!(
+        Ok(date_time(2019, 10, 1, 8, 30, 0)),
+        parse("the 1st of October")
+    );
+    assert_eq!(Ok(date_time(2021, 6, 5, 8, 30, 0)), parse("5 June, 2021"));
+}
+
+#[test]
+fn test_parse_times() {
+    assert_eq!(
+        Ok(date_time(2018, 10, 3, 17, 0, 0)),
+        parse("tomorrow at 5pm")
+    );
+    assert_eq!(Ok(date_time(2018, 10, 2, 17, 30, 0)), parse("at 17:30"));
+    assert_eq!(
+        Ok(date_time(2018, 10, 5, 17, 45, 0)),
+        parse("5:45 p.m. on Friday")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 3, 0, 0, 0)),
+        parse("midnight tomorrow")
+    );
+    assert_eq!(Ok(date_time(2018, 10, 2, 0, 15, 0)), parse("12:15am"));
+    assert_eq!(
+        Ok(date_time(2018, 10, 5, 9, 0, 0)),
+        parse("next friday at 9")
+    );
+}
+
+#[test]
+fn test_parse_dates_only() {
+    assert_eq!(
+        Ok(DateTuple::new(2018, 10, 1).unwrap()),
+        parse_date("yesterday")
+    );
+    assert_eq!(
+        Ok(DateTuple::new(2018, 11, 2).unwrap()),
+        parse_date("in a month")
+    );
+    assert_eq!(
+        Err(parse_error("tomorrow at 5pm", 9, "the end of the input")),
+        parse_date("tomorrow at 5pm")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "in 3 hours",
+            5,
+            "a unit such as days, weeks, or months"
+        )),
+        parse_date("in 3 hours")
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err(parse_error(
+            "someday",
+            0,
+            "a date or time such as \"tomorrow at 5pm\", \"next Friday\", or \"in 2 hours\""
+        )),
+        parse("someday")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "in two fortnights",
+            7,
+            "a unit such as hours, days, or weeks"
+        )),
+        parse("in two fortnights")
+    );
+    assert_eq!(
+        Err(parse_error("3 days", 6, "\"ago\" or \"from now\"")),
+        parse("3 days")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "tomorrow at 25",
+            12,
+            "an hour between 0 and 23"
+        )),
+        parse("tomorrow at 25")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "next blue moon",
+            5,
+            "a weekday, week, month, or year"
+        )),
+        parse("next blue moon")
+    );
+    match parse("in 9999 years") {
+        Err(Error::OutOfRange { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match parse("February 30") {
+        Err(Error::InvalidDay { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
@@ -0,0 +1,99 @@
+extern crate date_time;
+
+mod common;
+
+use common::date_time;
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::offset_date_time::OffsetDateTime;
+use date_time::time_tuple::TimeTuple;
+use date_time::utc_offset::UtcOffset;
+use date_time::Error;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+fn offset(hours: i8, minutes: i8) -> UtcOffset {
+    UtcOffset::new(hours, minutes, 0).unwrap()
+}
+
+#[test]
+fn test_utc_conversion() {
+    let local = OffsetDateTime::new(date_time(2018, 10, 2, 1, 30, 0), offset(2, 0)).unwrap();
+    assert_eq!(date_time(2018, 10, 2, 1, 30, 0), local.get_date_time());
+    assert_eq!(offset(2, 0), local.get_offset());
+    assert_eq!(date_time(2018, 10, 1, 23, 30, 0), local.to_utc());
+
+    let from_utc =
+        OffsetDateTime::from_utc(date_time(2018, 10, 2, 8, 30, 0), offset(-5, -30)).unwrap();
+    assert_eq!(date_time(2018, 10, 2, 3, 0, 0), from_utc.get_date_time());
+    assert_eq!(DateTuple::new(2018, 10, 2).unwrap(), from_utc.get_date());
+    assert_eq!(TimeTuple::new(3, 0, 0), from_utc.get_time());
+
+    let moved = from_utc.to_offset(offset(9, 0)).unwrap();
+    assert_eq!(date_time(2018, 10, 2, 17, 30, 0), moved.get_date_time());
+    assert_eq!(from_utc, moved);
+}
+
+#[test]
+fn test_range() {
+    match OffsetDateTime::new(DateTimeTuple::min_value(), offset(1, 0)) {
+        Err(Error::OutOfRange { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert!(OffsetDateTime::new(DateTimeTuple::min_value(), offset(-1, 0)).is_ok());
+    assert!(OffsetDateTime::from_utc(DateTimeTuple::max_value(), offset(1, 0)).is_err());
+    assert!(OffsetDateTime::from_utc(DateTimeTuple::max_value(), offset(-1, 0)).is_ok());
+}
+
+#[test]
+fn test_comparison_by_instant() {
+    let a = OffsetDateTime::new(date_time(2018, 10, 2, 10, 0, 0), offset(2, 0)).unwrap();
+    let b = OffsetDateTime::new(date_time(2018, 10, 2, 8, 0, 0), UtcOffset::utc()).unwrap();
+    let c = OffsetDateTime::new(date_time(2018, 10, 2, 9, 0, 0), offset(-1, 0)).unwrap();
+    assert_eq!(a, b);
+    assert!(c > a);
+    assert!(c.get_date_time() < a.get_date_time());
+    let set: HashSet<OffsetDateTime> = vec![a, b, c].into_iter().collect();
+    assert_eq!(2, set.len());
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!(
+        "2018-10-02T10:30:00+02:00",
+        OffsetDateTime::new(date_time(2018, 10, 2, 10, 30, 0), offset(2, 0))
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "2018-10-02T08:30:00Z",
+        OffsetDateTime::new(date_time(2018, 10, 2, 8, 30, 0), UtcOffset::utc())
+            .unwrap()
+            .to_string()
+    );
+}
+
+#[test]
+fn test_from_string() {
+    let parsed = OffsetDateTime::from_str("2018-10-02T10:30:00+02:00").unwrap();
+    assert_eq!(date_time(2018, 10, 2, 10, 30, 0), parsed.get_date_time());
+    assert_eq!(offset(2, 0), parsed.get_offset());
+    assert_eq!("2018-10-02T10:30:00+02:00", parsed.to_string());
+    assert_eq!(
+        UtcOffset::utc(),
+        OffsetDateTime::from_str("2018-10-02T08:30:00Z")
+            .unwrap()
+            .get_offset()
+    );
+    assert_eq!(
+        offset(-5, -30),
+        OffsetDateTime::from_str("2018-10-02 03:00:00.25-05:30")
+            .unwrap()
+            .get_offset()
+    );
+    assert!(OffsetDateTime::from_str("2018-10-02T10:30:00").is_err());
+    assert_eq!(
+        Err(Error::InvalidOffset { seconds: 72000 }),
+        OffsetDateTime::from_str("2018-10-02T10:30:00+20:00")
+    );
+}
@@ -0,0 +1,205 @@
+extern crate date_time;
+
+mod common;
+
+use common::{date_time, parse_error};
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::error::TypeName;
+use date_time::format::Pattern;
+use date_time::month_tuple::MonthTuple;
+use date_time::time_tuple::{Duration, TimeTuple};
+use date_time::Error;
+
+#[test]
+fn test_parse_date_time() {
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 20, 30, 0)),
+        DateTimeTuple::parse_from_str("Oct 2, 2018 8:30 PM", "%b %-d, %Y %-I:%M %p")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 20, 30, 0)),
+        DateTimeTuple::parse_from_str("2018.10.02 20:30", "%Y.%m.%d %H:%M")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 0, 15, 9)),
+        DateTimeTuple::parse_from_str("2018-10-02T12:15:09 am", "%FT%I:%M:%S %p")
+    );
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 12, 15, 0)),
+        DateTimeTuple::parse_from_str("Tuesday 2 October 2018 12:15 PM", "%A %-d %B %Y %I:%M %p")
+    );
+}
+
+#[test]
+fn test_parse_date() {
+    let expected = Ok(DateTuple::new(2018, 10, 2).unwrap());
+    assert_eq!(
+        expected,
+        DateTuple::parse_from_str("02/10/2018", "%d/%m/%Y")
+    );
+    assert_eq!(expected, DateTuple::parse_from_str("2/10/18", "%-d/%-m/%y"));
+    assert_eq!(expected, DateTuple::parse_from_str("20181002", "%Y%m%d"));
+    assert_eq!(expected, DateTuple::parse_from_str("2018-275", "%Y-%j"));
+    assert_eq!(
+        expected,
+        DateTuple::parse_from_str("2018-W40-2", "%G-W%V-%u")
+    );
+    assert_eq!(
+        expected,
+        DateTuple::parse_from_str("tue OCT 02 2018", "%a %b %d %Y")
+    );
+    assert_eq!(
+        Ok(DateTuple::new(1969, 1, 1).unwrap()),
+        DateTuple::parse_from_str("01/01/69", "%d/%m/%y")
+    );
+    assert_eq!(
+        Ok(DateTuple::new(2068, 1, 1).unwrap()),
+        DateTuple::parse_from_str("01/01/68", "%d/%m/%y")
+    );
+}
+
+#[test]
+fn test_parse_other_types() {
+    assert_eq!(
+        Ok(MonthTuple::new(2018, 10).unwrap()),
+        MonthTuple::parse_from_str("October 2018", "%B %Y")
+    );
+    assert_eq!(
+        Ok(TimeTuple::new(20, 30, 0)),
+        TimeTuple::parse_from_str("8:30 PM", "%-I:%M %p")
+    );
+    assert_eq!(
+        Ok(TimeTuple::new(8, 5, 9)),
+        TimeTuple::parse_from_str("08:05:09", "%T")
+    );
+    assert_eq!(
+        Ok(Duration::new(150, 5, 0)),
+        Duration::parse_from_str("150h 05m", "%Hh %Mm")
+    );
+    assert_eq!(
+        Ok(Duration::new(3, 0, 0)),
+        Duration::parse_from_str("3h", "%-Hh")
+    );
+}
+
+#[test]
+fn test_parse_with_compiled_pattern() {
+    let pattern = Pattern::new("%d/%m/%Y %H:%M").unwrap();
+    assert_eq!(
+        Ok(date_time(2018, 10, 2, 20, 30, 0)),
+        DateTimeTuple::parse_with("02/10/2018 20:30", &pattern)
+    );
+    assert_eq!(
+        Ok(date_time(2019, 1, 31, 6, 0, 0)),
+        DateTimeTuple::parse_with("31/01/2019 06:00", &pattern)
+    );
+}
+
+#[test]
+fn test_parse_round_trip() {
+    let pattern = Pattern::new("%a %d %b %Y %T").unwrap();
+    let tuple = date_time(2024, 2, 29, 23, 59, 1);
+    let formatted = tuple.format_with(&pattern).unwrap();
+    assert_eq!(Ok(tuple), DateTimeTuple::parse_with(&formatted, &pattern));
+}
+
+#[test]
+fn test_parse_error_positions() {
+    assert_eq!(
+        Err(parse_error("02-10-2018", 2, "\"/\"")),
+        DateTuple::parse_from_str("02-10-2018", "%d/%m/%Y")
+    );
+    assert_eq!(
+        Err(parse_error("02/13/2018", 3, "a month between 1 and 12")),
+        DateTuple::parse_from_str("02/13/2018", "%d/%m/%Y")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "2/10/2018",
+            0,
+            "a day of the month of 2 digits"
+        )),
+        DateTuple::parse_from_str("2/10/2018", "%d/%m/%Y")
+    );
+    assert_eq!(
+        Err(parse_error("02/10/2018 extra", 10, "the end of the input")),
+        DateTuple::parse_from_str("02/10/2018 extra", "%d/%m/%Y")
+    );
+    assert_eq!(
+        Err(parse_error("Okt 2018", 0, "an abbreviated month name")),
+        MonthTuple::parse_from_str("Okt 2018", "%b %Y")
+    );
+    assert_eq!(
+        Err(parse_error("8:30 XM", 5, "AM or PM")),
+        TimeTuple::parse_from_str("8:30 XM", "%-I:%M %p")
+    );
+    assert_eq!(
+        Err(parse_error("é 2018", 0, "a year of 4 digits")),
+        DateTuple::parse_from_str("é 2018", "%Y")
+    );
+}
+
+#[test]
+fn test_parse_inconsistent_fields() {
+    assert_eq!(
+        Err(parse_error(
+            "Mon 2 Oct 2018",
+            0,
+            "Tuesday to agree with the rest of the input"
+        )),
+        DateTuple::parse_from_str("Mon 2 Oct 2018", "%a %-d %b %Y")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "2018-10-02 2019",
+            11,
+            "the same value as earlier in the input"
+        )),
+        DateTuple::parse_from_str("2018-10-02 2019", "%F %Y")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "13:00 AM",
+            6,
+            "PM to agree with the rest of the input"
+        )),
+        TimeTuple::parse_from_str("13:00 AM", "%H:%M %p")
+    );
+}
+
+#[test]
+fn test_parse_invalid_values() {
+    match DateTuple::parse_from_str("30/02/2018", "%d/%m/%Y") {
+        Err(Error::InvalidDay { .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert_eq!(
+        Err(Error::IncompletePattern {
+            type_name: TypeName::DateTuple,
+            missing: "day"
+        }),
+        DateTuple::parse_from_str("10/2018", "%m/%Y")
+    );
+    assert_eq!(
+        Err(Error::IncompletePattern {
+            type_name: TypeName::DateTimeTuple,
+            missing: "hour"
+        }),
+        DateTimeTuple::parse_from_str("2018-10-02", "%F")
+    );
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::MonthTuple,
+            directive: 'd'
+        }),
+        MonthTuple::parse_from_str("02 Oct 2018", "%d %b %Y")
+    );
+    assert_eq!(
+        Err(Error::UnsupportedDirective {
+            type_name: TypeName::Duration,
+            directive: 'p'
+        }),
+        Duration::parse_from_str("1 PM", "%-H %p")
+    );
+}
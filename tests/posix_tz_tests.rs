@@ -0,0 +1,221 @@
+extern crate date_time;
+
+mod common;
+
+use common::{date_time, parse_error};
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::posix_tz::PosixTz;
+use date_time::utc_offset::UtcOffset;
+use std::str::FromStr;
+
+fn posix_tz(s: &str) -> PosixTz {
+    PosixTz::from_str(s).unwrap()
+}
+
+/// Gets the abbreviation in effect at a UTC date and time.
+fn abbreviation_at(tz: &PosixTz, utc: DateTimeTuple) -> &str {
+    tz.local_time_type_at(utc).get_abbreviation()
+}
+
+#[test]
+fn test_parse_standard_time_only() {
+    let tz = posix_tz("JST-9");
+    assert_eq!("JST", tz.get_standard().get_abbreviation());
+    assert_eq!(
+        UtcOffset::new(9, 0, 0).unwrap(),
+        tz.get_standard().get_offset()
+    );
+    assert!(!tz.get_standard().is_dst());
+    assert_eq!(None, tz.get_daylight_saving());
+    assert_eq!("JST-9", tz.to_string());
+
+    let tz = posix_tz("<-03>3");
+    assert_eq!("-03", tz.get_standard().get_abbreviation());
+    assert_eq!(
+        UtcOffset::new(-3, 0, 0).unwrap(),
+        tz.get_standard().get_offset()
+    );
+
+    let tz = posix_tz("<+0530>-5:30");
+    assert_eq!(
+        UtcOffset::new(5, 30, 0).unwrap(),
+        tz.get_standard().get_offset()
+    );
+    assert_eq!(
+        "+0530",
+        abbreviation_at(&tz, date_time(2018, 10, 2, 8, 30, 0))
+    );
+}
+
+#[test]
+fn test_northern_hemisphere_rules() {
+    let tz = posix_tz("CET-1CEST,M3.5.0,M10.5.0/3");
+    let dst = tz.get_daylight_saving().unwrap();
+    assert_eq!("CEST", dst.get_abbreviation());
+    assert_eq!(UtcOffset::new(2, 0, 0).unwrap(), dst.get_offset());
+    assert!(dst.is_dst());
+    // 2018-03-25 and 2018-10-28 are the last Sundays of March and October.
+    assert_eq!(
+        "CET",
+        abbreviation_at(&tz, date_time(2018, 3, 25, 0, 59, 0))
+    );
+    assert_eq!(
+        "CEST",
+        abbreviation_at(&tz, date_time(2018, 3, 25, 1, 0, 0))
+    );
+    assert_eq!(
+        "CEST",
+        abbreviation_at(&tz, date_time(2018, 10, 28, 0, 59, 0))
+    );
+    assert_eq!(
+        "CET",
+        abbreviation_at(&tz, date_time(2018, 10, 28, 1, 0, 0))
+    );
+    assert_eq!("CET", abbreviation_at(&tz, date_time(0, 1, 1, 0, 0, 0)));
+    assert_eq!("CEST", abbreviation_at(&tz, date_time(9999, 7, 1, 0, 0, 0)));
+    assert_eq!(
+        "CET",
+        abbreviation_at(&tz, date_time(9999, 12, 31, 23, 59, 0))
+    );
+}
+
+#[test]
+fn test_southern_hemisphere_rules() {
+    let tz = posix_tz("AEST-10AEDT,M10.1.0,M4.1.0/3");
+    assert_eq!("AEDT", abbreviation_at(&tz, date_time(2019, 1, 1, 0, 0, 0)));
+    // Summer time ends at 03:00 AEDT on 2019-04-07, and starts at 02:00 AEST on 2019-10-06.
+    assert_eq!(
+        "AEDT",
+        abbreviation_at(&tz, date_time(2019, 4, 6, 15, 59, 0))
+    );
+    assert_eq!(
+        "AEST",
+        abbreviation_at(&tz, date_time(2019, 4, 6, 16, 0, 0))
+    );
+    assert_eq!(
+        "AEST",
+        abbreviation_at(&tz, date_time(2019, 10, 5, 15, 59, 0))
+    );
+    assert_eq!(
+        "AEDT",
+        abbreviation_at(&tz, date_time(2019, 10, 5, 16, 0, 0))
+    );
+    assert_eq!("AEDT", abbreviation_at(&tz, date_time(0, 1, 1, 0, 0, 0)));
+}
+
+#[test]
+fn test_other_rule_forms() {
+    // Without rules, daylight saving time follows the United States.
+    let tz = posix_tz("EST5EDT");
+    assert_eq!(
+        "EST",
+        abbreviation_at(&tz, date_time(2018, 3, 11, 6, 59, 0))
+    );
+    assert_eq!("EDT", abbreviation_at(&tz, date_time(2018, 3, 11, 7, 0, 0)));
+    assert_eq!(
+        "EDT",
+        abbreviation_at(&tz, date_time(2018, 11, 4, 5, 59, 0))
+    );
+    assert_eq!("EST", abbreviation_at(&tz, date_time(2018, 11, 4, 6, 0, 0)));
+
+    // J60 is always 1st March, whereas 59 is 29th February in a leap year.
+    let tz = posix_tz("XST0XDT,J60/0,J305/0");
+    assert_eq!("XDT", abbreviation_at(&tz, date_time(2020, 3, 1, 0, 0, 0)));
+    assert_eq!(
+        "XST",
+        abbreviation_at(&tz, date_time(2020, 2, 29, 23, 59, 0))
+    );
+    let tz = posix_tz("XST0XDT,59/0,304/0");
+    assert_eq!("XDT", abbreviation_at(&tz, date_time(2020, 2, 29, 0, 0, 0)));
+    assert_eq!(
+        "XST",
+        abbreviation_at(&tz, date_time(2019, 2, 28, 23, 59, 0))
+    );
+    assert_eq!("XDT", abbreviation_at(&tz, date_time(2019, 3, 1, 0, 0, 0)));
+
+    // Times past midnight, as in TZif version 3.
+    let tz = posix_tz("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1");
+    assert_eq!(
+        UtcOffset::new(-2, 0, 0).unwrap(),
+        tz.get_daylight_saving().unwrap().get_offset()
+    );
+    // Summer time starts at 22:00 local time on 2018-03-24, the day before the last Sunday.
+    assert_eq!(
+        "-03",
+        abbreviation_at(&tz, date_time(2018, 3, 25, 0, 59, 0))
+    );
+    assert_eq!("-02", abbreviation_at(&tz, date_time(2018, 3, 25, 1, 0, 0)));
+
+    // Daylight saving time all year round.
+    let tz = posix_tz("EST5EDT,0/0,J365/25");
+    for &(month, day) in &[(1, 1), (6, 1), (12, 31)] {
+        assert_eq!(
+            "EDT",
+            abbreviation_at(&tz, date_time(2019, month, day, 5, 0, 0))
+        );
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Err(parse_error(
+            "",
+            0,
+            "an abbreviation of at least three letters, or one in angle brackets such as <+03>"
+        )),
+        PosixTz::from_str("")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "UTC",
+            3,
+            "an offset from UTC in hours, such as 5 or -1"
+        )),
+        PosixTz::from_str("UTC")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "<+03-3",
+            6,
+            "letters, digits, or signs, ending with >"
+        )),
+        PosixTz::from_str("<+03-3")
+    );
+    assert_eq!(
+        Err(parse_error("XST-20", 3, "an offset of at most 18 hours")),
+        PosixTz::from_str("XST-20")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "CET-1CEST,M3.5.0",
+            16,
+            "a comma followed by the end of daylight saving time"
+        )),
+        PosixTz::from_str("CET-1CEST,M3.5.0")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "CET-1CEST,M3.6.0,M10.5.0",
+            13,
+            "a week of the month between 1 and 5"
+        )),
+        PosixTz::from_str("CET-1CEST,M3.6.0,M10.5.0")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "CET-1CEST,J0,J300",
+            11,
+            "a day of the year between 1 and 365"
+        )),
+        PosixTz::from_str("CET-1CEST,J0,J300")
+    );
+    assert_eq!(
+        Err(parse_error(
+            "CET-1CEST,M3.5.0,M10.5.0/3x",
+            26,
+            "the end of the input"
+        )),
+        PosixTz::from_str("CET-1CEST,M3.5.0,M10.5.0/3x")
+    );
+}
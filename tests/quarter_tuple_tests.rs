@@ -0,0 +1,134 @@
+extern crate date_time;
+
+use date_time::clock::FixedClock;
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::month_tuple::MonthTuple;
+use date_time::quarter_tuple::QuarterTuple;
+use date_time::Error;
+
+#[test]
+fn test_this_quarter_does_not_panic() {
+    QuarterTuple::this_quarter();
+}
+
+#[test]
+fn test_this_quarter_with_clock() {
+    let clock = FixedClock::new(DateTimeTuple::from_rfc3339("2018-09-30T23:59:59Z").unwrap());
+    assert_eq!(
+        QuarterTuple::new(2018, 3).unwrap(),
+        QuarterTuple::this_quarter_with_clock(&clock)
+    );
+    clock.set(DateTimeTuple::from_rfc3339("2018-10-01T00:00:00Z").unwrap());
+    assert_eq!(
+        QuarterTuple::new(2018, 4).unwrap(),
+        QuarterTuple::this_quarter_with_clock(&clock)
+    );
+}
+
+#[test]
+fn test_validity() {
+    assert!(QuarterTuple::new(2000, 1).is_ok());
+    assert!(QuarterTuple::new(9999, 4).is_ok());
+    assert!(QuarterTuple::new(2000, 0).is_err());
+    assert!(QuarterTuple::new(2000, 5).is_err());
+    assert!(QuarterTuple::new(10000, 1).is_err());
+}
+
+#[test]
+fn test_getters() {
+    let tuple = QuarterTuple::new(2024, 3).unwrap();
+    assert_eq!(2024, tuple.get_year());
+    assert_eq!(3, tuple.get_quarter());
+}
+
+#[test]
+fn test_to_string() {
+    let tuple = QuarterTuple::new(2024, 3).unwrap();
+    assert_eq!(String::from("2024-Q3"), tuple.to_string());
+    assert_eq!(String::from("Q3 2024"), tuple.to_readable_string());
+}
+
+#[test]
+fn test_from_string() {
+    let tuple = QuarterTuple::new(2024, 3).unwrap();
+    assert_eq!(tuple, str::parse("2024-Q3").unwrap());
+    assert_eq!(tuple, str::parse("2024Q3").unwrap());
+    match str::parse::<QuarterTuple>("2024-Q5") {
+        Err(Error::InvalidParsedValue { error, .. }) => match *error {
+            Error::InvalidQuarter { .. } => (),
+            e => panic!("Unexpected error: {:?}", e),
+        },
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert!(str::parse::<QuarterTuple>("2024-3").is_err());
+}
+
+#[test]
+fn test_comparisons() {
+    let tuple1 = QuarterTuple::new(2000, 4).unwrap();
+    let tuple2 = QuarterTuple::new(2001, 1).unwrap();
+    let tuple3 = QuarterTuple::new(2001, 2).unwrap();
+    assert!(tuple1 < tuple2);
+    assert!(tuple2 < tuple3);
+    assert!(tuple3 > tuple1);
+}
+
+#[test]
+fn test_add_and_subtract_quarters() {
+    let mut tuple = QuarterTuple::new(2000, 3).unwrap();
+    tuple.add_quarters(6);
+    assert_eq!(QuarterTuple::new(2002, 1).unwrap(), tuple);
+    tuple.subtract_quarters(5);
+    assert_eq!(QuarterTuple::new(2000, 4).unwrap(), tuple);
+    tuple.add_quarters(u32::MAX);
+    assert_eq!(QuarterTuple::new(9999, 4).unwrap(), tuple);
+    tuple.subtract_quarters(u32::MAX);
+    assert_eq!(QuarterTuple::new(0, 1).unwrap(), tuple);
+}
+
+#[test]
+fn test_add_and_subtract_years() {
+    let mut tuple = QuarterTuple::new(9998, 2).unwrap();
+    tuple.add_years(2);
+    assert_eq!(QuarterTuple::new(9999, 2).unwrap(), tuple);
+    let mut tuple2 = QuarterTuple::new(2000, 1).unwrap();
+    tuple2.add_years(u16::MAX);
+    assert_eq!(QuarterTuple::new(9999, 1).unwrap(), tuple2);
+    tuple.subtract_years(10000);
+    assert_eq!(QuarterTuple::new(0, 2).unwrap(), tuple);
+}
+
+#[test]
+fn test_months_and_dates() {
+    let tuple = QuarterTuple::new(2024, 1).unwrap();
+    assert_eq!(
+        [
+            MonthTuple::new(2024, 1).unwrap(),
+            MonthTuple::new(2024, 2).unwrap(),
+            MonthTuple::new(2024, 3).unwrap()
+        ],
+        tuple.get_months()
+    );
+    assert_eq!(DateTuple::new(2024, 1, 1).unwrap(), tuple.get_first_date());
+    assert_eq!(DateTuple::new(2024, 3, 31).unwrap(), tuple.get_last_date());
+    let tuple = QuarterTuple::new(2024, 3).unwrap();
+    assert_eq!(DateTuple::new(2024, 7, 1).unwrap(), tuple.get_first_date());
+    assert_eq!(DateTuple::new(2024, 9, 30).unwrap(), tuple.get_last_date());
+}
+
+#[test]
+fn test_from_month_and_date() {
+    assert_eq!(
+        QuarterTuple::new(2024, 1).unwrap(),
+        QuarterTuple::from(MonthTuple::new(2024, 3).unwrap())
+    );
+    assert_eq!(
+        QuarterTuple::new(2024, 2).unwrap(),
+        QuarterTuple::from(MonthTuple::new(2024, 4).unwrap())
+    );
+    assert_eq!(
+        QuarterTuple::new(2024, 4).unwrap(),
+        QuarterTuple::from(DateTuple::new(2024, 12, 31).unwrap())
+    );
+}
@@ -0,0 +1,173 @@
+extern crate date_time;
+
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::relative::{RelativeFormatter, Unit};
+use date_time::time_tuple::Duration;
+
+fn reference() -> DateTimeTuple {
+    DateTimeTuple::from_rfc3339("2018-10-02T08:30:00Z").unwrap()
+}
+
+fn seconds_ago(seconds: u64) -> DateTimeTuple {
+    reference() - Duration::from_seconds(seconds)
+}
+
+fn seconds_ahead(seconds: u64) -> DateTimeTuple {
+    reference() + Duration::from_seconds(seconds)
+}
+
+#[test]
+fn test_humanize_date_times() {
+    let now = reference();
+    assert_eq!("just now", now.humanize_relative_to(now));
+    assert_eq!("just now", seconds_ago(9).humanize_relative_to(now));
+    assert_eq!("30 seconds ago", seconds_ago(30).humanize_relative_to(now));
+    assert_eq!("1 minute ago", seconds_ago(60).humanize_relative_to(now));
+    assert_eq!(
+        "in 59 minutes",
+        seconds_ahead(3599).humanize_relative_to(now)
+    );
+    assert_eq!("1 hour ago", seconds_ago(5400).humanize_relative_to(now));
+    assert_eq!(
+        "yesterday",
+        seconds_ago(26 * 3600).humanize_relative_to(now)
+    );
+    assert_eq!(
+        "tomorrow",
+        seconds_ahead(24 * 3600).humanize_relative_to(now)
+    );
+    assert_eq!(
+        "in 3 days",
+        seconds_ahead(3 * 86400).humanize_relative_to(now)
+    );
+}
+
+#[test]
+fn test_humanize_dates() {
+    let today = DateTuple::new(2018, 10, 2).unwrap();
+    let date = |y, m, d| DateTuple::new(y, m, d).unwrap().humanize_relative_to(today);
+    assert_eq!("today", date(2018, 10, 2));
+    assert_eq!("yesterday", date(2018, 10, 1));
+    assert_eq!("tomorrow", date(2018, 10, 3));
+    assert_eq!("6 days ago", date(2018, 9, 26));
+    assert_eq!("last week", date(2018, 9, 24));
+    assert_eq!("1 week ago", date(2018, 9, 22));
+    assert_eq!("in 3 weeks", date(2018, 10, 23));
+    assert_eq!("in 1 month", date(2018, 10, 31));
+    assert_eq!("next month", date(2018, 11, 1));
+    assert_eq!("2 months ago", date(2018, 7, 24));
+    assert_eq!("last year", date(2017, 9, 1));
+    assert_eq!("in 2 years", date(2020, 11, 1));
+}
+
+#[test]
+fn test_calendar_words_need_adjacent_periods() {
+    let new_year = DateTuple::new(2019, 1, 1).unwrap();
+    assert_eq!(
+        "1 year ago",
+        DateTuple::new(2017, 12, 31)
+            .unwrap()
+            .humanize_relative_to(new_year)
+    );
+    assert_eq!(
+        "last year",
+        DateTuple::new(2018, 1, 1)
+            .unwrap()
+            .humanize_relative_to(new_year)
+    );
+    let now = reference();
+    assert_eq!(
+        "1 day ago",
+        seconds_ago(33 * 3600 + 1800).humanize_relative_to(now)
+    );
+    assert_eq!(
+        "yesterday",
+        seconds_ago(30 * 3600).humanize_relative_to(now)
+    );
+}
+
+#[test]
+fn test_thresholds() {
+    let now = reference();
+    let formatter = RelativeFormatter::new()
+        .with_just_now(0)
+        .with_threshold(Unit::Second, 45)
+        .with_threshold(Unit::Minute, 45)
+        .with_threshold(Unit::Year, 0);
+    assert_eq!(
+        "1 second ago",
+        formatter.humanize_date_time(seconds_ago(1), now)
+    );
+    assert_eq!(
+        "1 minute ago",
+        formatter.humanize_date_time(seconds_ago(50), now)
+    );
+    assert_eq!(
+        "in 1 hour",
+        formatter.humanize_date_time(seconds_ahead(3000), now)
+    );
+    assert_eq!(
+        "in 2 years",
+        formatter.humanize_date_time(seconds_ahead(800 * 86400), now)
+    );
+    assert_eq!(
+        "last week",
+        RelativeFormatter::new()
+            .with_threshold(Unit::Day, 5)
+            .humanize_date(
+                DateTuple::new(2018, 9, 27).unwrap(),
+                DateTuple::new(2018, 10, 2).unwrap()
+            )
+    );
+    assert_eq!(
+        "in 1 week",
+        RelativeFormatter::new()
+            .with_threshold(Unit::Day, 5)
+            .humanize_date(
+                DateTuple::new(2018, 10, 7).unwrap(),
+                DateTuple::new(2018, 10, 2).unwrap()
+            )
+    );
+}
+
+#[test]
+fn test_granularity() {
+    let now = reference();
+    let days = RelativeFormatter::new().with_granularity(Unit::Day);
+    assert_eq!("today", days.humanize_date_time(seconds_ago(3 * 3600), now));
+    assert_eq!(
+        "yesterday",
+        days.humanize_date_time(seconds_ago(10 * 3600), now)
+    );
+    assert_eq!(
+        "yesterday",
+        days.humanize_date_time(seconds_ago(86400), now)
+    );
+    let minutes = RelativeFormatter::new().with_granularity(Unit::Minute);
+    assert_eq!("just now", minutes.humanize_date_time(seconds_ago(50), now));
+    assert_eq!(
+        "in 2 minutes",
+        minutes.humanize_date_time(seconds_ahead(150), now)
+    );
+    let months = RelativeFormatter::new().with_granularity(Unit::Month);
+    let today = DateTuple::new(2018, 10, 2).unwrap();
+    assert_eq!(
+        "this month",
+        months.humanize_date(DateTuple::new(2018, 10, 20).unwrap(), today)
+    );
+    assert_eq!(
+        "last month",
+        months.humanize_date(DateTuple::new(2018, 9, 20).unwrap(), today)
+    );
+    assert_eq!(
+        "1 month ago",
+        months.humanize_date(DateTuple::new(2018, 8, 20).unwrap(), today)
+    );
+    assert_eq!(
+        "tomorrow",
+        RelativeFormatter::new()
+            .with_granularity(Unit::Hour)
+            .humanize_date(DateTuple::new(2018, 10, 3).unwrap(), today)
+    );
+}
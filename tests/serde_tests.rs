@@ -0,0 +1,134 @@
+#![cfg(feature = "serde_support")]
+
+extern crate date_time;
+extern crate serde;
+extern crate serde_test;
+
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::offset_date_time::OffsetDateTime;
+use date_time::time_tuple::Duration;
+use date_time::utc_offset::UtcOffset;
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[derive(PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+struct Timeouts {
+    default: Duration,
+    #[serde(with = "date_time::time_tuple::iso8601_duration")]
+    iso: Duration,
+}
+
+#[test]
+fn test_duration_default_format() {
+    assert_tokens(&Duration::new(8, 30, 5), &[Token::Str("8:30:05")]);
+    assert_de_tokens_error::<Duration>(
+        &[Token::Str("PT8H")],
+        "Invalid str formatting of Duration: PT8H\nExpects a string formatted like 8:30:05",
+    );
+    assert_de_tokens_error::<Duration>(
+        &[Token::Str("99999999999:00:00")],
+        "Unable to parse 99999999999:00:00 at position 0\nExpected an hour between 0 and 4294967295.",
+    );
+}
+
+#[test]
+fn test_duration_iso8601_format() {
+    assert_tokens(
+        &Timeouts {
+            default: Duration::new(1, 30, 0),
+            iso: Duration::new(1, 30, 0),
+        },
+        &[
+            Token::Struct {
+                name: "Timeouts",
+                len: 2,
+            },
+            Token::Str("default"),
+            Token::Str("1:30:00"),
+            Token::Str("iso"),
+            Token::Str("PT1H30M"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+fn offset_date_time_tokens(year: u16, offset_seconds: i32) -> Vec<Token> {
+    vec![
+        Token::Struct {
+            name: "OffsetDateTime",
+            len: 2,
+        },
+        Token::Str("dt"),
+        Token::Struct {
+            name: "DateTimeTuple",
+            len: 2,
+        },
+        Token::Str("d"),
+        Token::Struct {
+            name: "DateTuple",
+            len: 3,
+        },
+        Token::Str("y"),
+        Token::U16(year),
+        Token::Str("m"),
+        Token::U8(1),
+        Token::Str("d"),
+        Token::U8(1),
+        Token::StructEnd,
+        Token::Str("t"),
+        Token::Struct {
+            name: "TimeTuple",
+            len: 3,
+        },
+        Token::Str("h"),
+        Token::U8(0),
+        Token::Str("m"),
+        Token::U8(0),
+        Token::Str("s"),
+        Token::U8(0),
+        Token::StructEnd,
+        Token::StructEnd,
+        Token::Str("offset"),
+        Token::Struct {
+            name: "UtcOffset",
+            len: 1,
+        },
+        Token::Str("s"),
+        Token::I32(offset_seconds),
+        Token::StructEnd,
+        Token::StructEnd,
+    ]
+}
+
+#[test]
+fn test_offset_date_time() {
+    let local = DateTimeTuple::from_rfc3339("2018-01-01T00:00:00Z").unwrap();
+    let odt = OffsetDateTime::new(local, UtcOffset::new(2, 0, 0).unwrap()).unwrap();
+    assert_tokens(&odt, &offset_date_time_tokens(2018, 7200));
+}
+
+#[test]
+fn test_invalid_offsets_are_rejected() {
+    assert_de_tokens_error::<UtcOffset>(
+        &[
+            Token::Struct {
+                name: "UtcOffset",
+                len: 1,
+            },
+            Token::Str("s"),
+            Token::I32(64801),
+            Token::StructEnd,
+        ],
+        &UtcOffset::from_seconds(64801).unwrap_err().to_string(),
+    );
+    assert_de_tokens_error::<OffsetDateTime>(
+        &offset_date_time_tokens(2018, 64801),
+        &UtcOffset::from_seconds(64801).unwrap_err().to_string(),
+    );
+    let local = DateTimeTuple::from_rfc3339("0000-01-01T00:00:00Z").unwrap();
+    assert_de_tokens_error::<OffsetDateTime>(
+        &offset_date_time_tokens(0, 3600),
+        &OffsetDateTime::new(local, UtcOffset::new(1, 0, 0).unwrap())
+            .unwrap_err()
+            .to_string(),
+    );
+}
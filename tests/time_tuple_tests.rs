@@ -1,6 +1,10 @@
+#![allow(deprecated)]
+
 extern crate date_time;
 
-use date_time::time_tuple::{Duration, Time, TimeTuple};
+use date_time::time_tuple::{Duration, SignedDuration, Time, TimeTuple};
+use date_time::Error;
+use std::convert::TryFrom;
 
 #[test]
 fn test_now_function_does_not_panic() {
@@ -161,3 +165,325 @@ fn test_large_duration() {
     let duration = Duration::new(200, 0, 0);
     assert_eq!(String::from("200:00:00"), duration.to_string());
 }
+
+#[test]
+fn test_duration_from_string_with_too_many_hours() {
+    assert_eq!(
+        Err(Error::ParseError {
+            input: String::from("99999999999:00:00"),
+            position: 0,
+            expected: String::from("an hour between 0 and 4294967295"),
+        }),
+        str::parse::<Duration>("99999999999:00:00")
+    );
+    assert_eq!(
+        Ok(Duration::new(u32::MAX, 0, 0)),
+        str::parse::<Duration>("4294967295:00:00")
+    );
+}
+
+#[test]
+fn test_duration_subtraction_borrows() {
+    assert_eq!(
+        Duration::new(1, 30, 0),
+        Duration::new(25, 0, 0) - Duration::new(23, 30, 0)
+    );
+    let mut duration = Duration::new(1, 0, 0);
+    duration -= Duration::new(0, 0, 1);
+    assert_eq!(Duration::new(0, 59, 59), duration);
+}
+
+#[test]
+fn test_signed_duration_from_seconds() {
+    let duration = SignedDuration::from_seconds(-5400);
+    assert!(duration.is_negative());
+    assert_eq!(1, duration.get_hours());
+    assert_eq!(30, duration.get_minutes());
+    assert_eq!(0, duration.get_seconds());
+    assert_eq!(-5400, duration.to_seconds());
+    assert!(!SignedDuration::from_seconds(0).is_negative());
+}
+
+#[test]
+fn test_large_durations_saturate() {
+    let max = Duration::new(u32::MAX, 59, 59);
+    assert_eq!(max, Duration::from_seconds(u64::MAX));
+    assert_eq!(max, Duration::new(u32::MAX, 60, 0));
+    let signed_max = SignedDuration::from(max);
+    assert_eq!(signed_max, signed_max + signed_max);
+    assert_eq!(-signed_max, SignedDuration::from_seconds(i64::MIN));
+}
+
+#[test]
+fn test_signed_duration_to_string() {
+    assert_eq!(
+        String::from("-1:30:00"),
+        SignedDuration::from_seconds(-5400).to_string()
+    );
+    assert_eq!(
+        String::from("1:30:05"),
+        SignedDuration::from_seconds(5405).to_string()
+    );
+}
+
+#[test]
+fn test_signed_duration_from_string() {
+    assert_eq!(
+        SignedDuration::from_seconds(-5400),
+        str::parse("-1:30:00").unwrap()
+    );
+    assert_eq!(
+        SignedDuration::from_seconds(5400),
+        str::parse("+1:30:00").unwrap()
+    );
+    assert_eq!(
+        SignedDuration::from_seconds(5400),
+        str::parse("1:30:00").unwrap()
+    );
+    assert!(!str::parse::<SignedDuration>("-0:00:00")
+        .unwrap()
+        .is_negative());
+    assert!(str::parse::<SignedDuration>("--1:30:00").is_err());
+    assert!(str::parse::<SignedDuration>("-1:3:00").is_err());
+    assert_eq!(
+        Err(Error::ParseError {
+            input: String::from("-99999999999:00:00"),
+            position: 1,
+            expected: String::from("an hour between 0 and 4294967295"),
+        }),
+        str::parse::<SignedDuration>("-99999999999:00:00")
+    );
+}
+
+#[test]
+fn test_signed_duration_negation_and_abs() {
+    let duration = SignedDuration::from_seconds(90);
+    assert_eq!(SignedDuration::from_seconds(-90), -duration);
+    assert_eq!(duration, -(-duration));
+    assert_eq!(
+        SignedDuration::from_seconds(0),
+        -SignedDuration::from_seconds(0)
+    );
+    assert_eq!(duration, (-duration).abs());
+    assert_eq!(Duration::new(0, 1, 30), (-duration).unsigned_abs());
+}
+
+#[test]
+fn test_signed_duration_arithmetic_and_comparison() {
+    let mut duration = SignedDuration::from_seconds(60);
+    duration -= SignedDuration::from_seconds(90);
+    assert_eq!(SignedDuration::from_seconds(-30), duration);
+    duration += SignedDuration::from_seconds(-30);
+    assert_eq!(SignedDuration::from_seconds(-60), duration);
+    assert!(duration < SignedDuration::from_seconds(0));
+    assert!(SignedDuration::from_seconds(-120) < duration);
+}
+
+#[test]
+fn test_signed_duration_conversions() {
+    let duration = Duration::new(1, 30, 0);
+    assert_eq!(
+        SignedDuration::from_seconds(5400),
+        SignedDuration::from(duration)
+    );
+    assert_eq!(
+        duration,
+        Duration::try_from(SignedDuration::from(duration)).unwrap()
+    );
+    assert!(Duration::try_from(-SignedDuration::from(duration)).is_err());
+}
+
+#[test]
+fn test_duration_checked_arithmetic() {
+    let duration = Duration::new(1, 0, 0);
+    assert_eq!(
+        Some(Duration::new(0, 59, 59)),
+        duration.checked_sub_seconds(1)
+    );
+    assert_eq!(
+        Some(Duration::new(0, 0, 0)),
+        duration.checked_sub_minutes(60)
+    );
+    assert_eq!(None, duration.checked_sub_minutes(61));
+    assert_eq!(None, duration.checked_sub_hours(2));
+    assert_eq!(None, duration.checked_sub_seconds(3601));
+    assert_eq!(
+        Some(Duration::new(1, 1, 0)),
+        duration.checked_add_seconds(60)
+    );
+    assert_eq!(
+        Some(Duration::new(2, 0, 0)),
+        duration.checked_add_minutes(60)
+    );
+    assert_eq!(
+        Some(Duration::new(u32::MAX, 0, 0)),
+        duration.checked_add_hours(u32::MAX - 1)
+    );
+    assert_eq!(None, duration.checked_add_hours(u32::MAX));
+    assert_eq!(None, Duration::new(u32::MAX, 59, 59).checked_add_seconds(1));
+    assert_eq!(None, Duration::new(u32::MAX, 59, 0).checked_add_minutes(1));
+}
+
+#[test]
+fn test_duration_saturating_arithmetic() {
+    let duration = Duration::new(1, 0, 0);
+    assert_eq!(Duration::new(0, 0, 0), duration.saturating_sub_hours(2));
+    assert_eq!(Duration::new(0, 0, 0), duration.saturating_sub_minutes(61));
+    assert_eq!(
+        Duration::new(0, 0, 0),
+        duration.saturating_sub_seconds(3601)
+    );
+    assert_eq!(Duration::new(0, 59, 59), duration.saturating_sub_seconds(1));
+    assert_eq!(
+        Duration::new(u32::MAX, 59, 59),
+        duration.saturating_add_hours(u32::MAX)
+    );
+    assert_eq!(
+        Duration::new(u32::MAX, 59, 59),
+        Duration::new(u32::MAX, 59, 0).saturating_add_minutes(5)
+    );
+    assert_eq!(Duration::new(1, 0, 1), duration.saturating_add_seconds(1));
+}
+
+#[test]
+fn test_duration_to_iso8601() {
+    assert_eq!("PT1H30M", Duration::new(1, 30, 0).to_iso8601());
+    assert_eq!("PT26H5S", Duration::new(26, 0, 5).to_iso8601());
+    assert_eq!("PT1M30S", Duration::new(0, 0, 90).to_iso8601());
+    assert_eq!("PT0S", Duration::new(0, 0, 0).to_iso8601());
+}
+
+#[test]
+fn test_duration_from_iso8601() {
+    assert_eq!(
+        Ok(Duration::new(1, 30, 0)),
+        Duration::from_iso8601("PT1H30M")
+    );
+    assert_eq!(
+        Ok(Duration::new(51, 0, 0)),
+        Duration::from_iso8601("P2DT3H")
+    );
+    assert_eq!(Ok(Duration::new(0, 1, 30)), Duration::from_iso8601("PT90S"));
+    assert_eq!(Ok(Duration::new(168, 0, 0)), Duration::from_iso8601("P1W"));
+    assert_eq!(
+        Ok(Duration::new(0, 0, 5)),
+        Duration::from_iso8601("PT5.75S")
+    );
+    assert_eq!(
+        Ok(Duration::new(0, 0, 5)),
+        Duration::from_iso8601("PT5,75S")
+    );
+    assert_eq!(
+        Ok(Duration::new(24, 0, 0)),
+        Duration::from_iso8601("P0Y0M1D")
+    );
+    assert_eq!(Ok(Duration::new(0, 0, 0)), Duration::from_iso8601("PT0S"));
+    let duration = Duration::new(12345, 6, 7);
+    assert_eq!(Ok(duration), Duration::from_iso8601(&duration.to_iso8601()));
+    assert_eq!(
+        Ok(Duration::new(u32::MAX, 59, 59)),
+        Duration::from_iso8601(&Duration::new(u32::MAX, 59, 59).to_iso8601())
+    );
+}
+
+#[test]
+fn test_duration_from_iso8601_errors() {
+    for s in &[
+        "", "P", "PT", "P1DT", "1H", "PT1.5H", "PT1H30", "pt1h", "P1H",
+    ] {
+        match Duration::from_iso8601(s) {
+            Err(Error::InvalidFormat { .. }) => (),
+            r => panic!("Unexpected result for {}: {:?}", s, r),
+        }
+    }
+    assert_eq!(
+        Err(Error::AmbiguousDuration {
+            input: String::from("P1Y")
+        }),
+        Duration::from_iso8601("P1Y")
+    );
+    assert_eq!(
+        Err(Error::AmbiguousDuration {
+            input: String::from("P2MT1H")
+        }),
+        Duration::from_iso8601("P2MT1H")
+    );
+    for s in &["PT4294967296H", "P99999999999999999999D", "P30000000000W"] {
+        match Duration::from_iso8601(s) {
+            Err(Error::OutOfRange { .. }) => (),
+            r => panic!("Unexpected result for {}: {:?}", s, r),
+        }
+    }
+}
+
+#[test]
+fn test_duration_to_human_string() {
+    assert_eq!("2d 3h", Duration::new(51, 0, 0).to_human_string());
+    assert_eq!("45m", Duration::new(0, 45, 0).to_human_string());
+    assert_eq!("1h 30m 15s", Duration::new(1, 30, 15).to_human_string());
+    assert_eq!("1d 5s", Duration::new(24, 0, 5).to_human_string());
+    assert_eq!("0s", Duration::new(0, 0, 0).to_human_string());
+}
+
+#[test]
+fn test_duration_parse_human() {
+    let expected = Ok(Duration::new(1, 30, 15));
+    assert_eq!(expected, Duration::parse_human("1h30m15s"));
+    assert_eq!(expected, Duration::parse_human("1h 30m 15s"));
+    assert_eq!(
+        expected,
+        Duration::parse_human("1 hour 30 minutes 15 seconds")
+    );
+    assert_eq!(expected, Duration::parse_human("1 HR, 30 Mins, 15 secs"));
+    assert_eq!(expected, Duration::parse_human("15s 1h 30m"));
+    assert_eq!(expected, Duration::parse_human("  90m15s  "));
+    assert_eq!(Ok(Duration::new(0, 45, 0)), Duration::parse_human("45 min"));
+    assert_eq!(Ok(Duration::new(0, 1, 30)), Duration::parse_human("90s"));
+    assert_eq!(Ok(Duration::new(52, 0, 0)), Duration::parse_human("2d4h"));
+    assert_eq!(
+        Ok(Duration::new(168, 0, 0)),
+        Duration::parse_human("1 week")
+    );
+    assert_eq!(Ok(Duration::new(2, 0, 0)), Duration::parse_human("1h1h"));
+    let duration = Duration::new(12345, 6, 7);
+    assert_eq!(
+        Ok(duration),
+        Duration::parse_human(&duration.to_human_string())
+    );
+}
+
+#[test]
+fn test_duration_parse_human_errors() {
+    let error = |input: &str, position: usize, expected: &str| {
+        Err(Error::ParseError {
+            input: input.to_string(),
+            position,
+            expected: expected.to_string(),
+        })
+    };
+    assert_eq!(error("", 0, "a number"), Duration::parse_human(""));
+    assert_eq!(error("  ", 2, "a number"), Duration::parse_human("  "));
+    assert_eq!(
+        error("90", 2, "a unit such as h, min, or seconds"),
+        Duration::parse_human("90")
+    );
+    assert_eq!(
+        error("1h 30x", 5, "a unit such as h, min, or seconds"),
+        Duration::parse_human("1h 30x")
+    );
+    assert_eq!(
+        error("1 hour and 30 minutes", 7, "a number"),
+        Duration::parse_human("1 hour and 30 minutes")
+    );
+    assert_eq!(error("h", 0, "a number"), Duration::parse_human("h"));
+    assert_eq!(
+        error("1.5h", 1, "a unit such as h, min, or seconds"),
+        Duration::parse_human("1.5h")
+    );
+    for s in &["4294967296h", "99999999999999999999s", "30000000000w"] {
+        match Duration::parse_human(s) {
+            Err(Error::OutOfRange { .. }) => (),
+            r => panic!("Unexpected result for {}: {:?}", s, r),
+        }
+    }
+}
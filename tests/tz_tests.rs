@@ -0,0 +1,252 @@
+extern crate date_time;
+
+mod common;
+
+use common::date_time;
+use date_time::tz::{self, TimeZone};
+use date_time::utc_offset::UtcOffset;
+use date_time::Error;
+use std::fs;
+use std::path::PathBuf;
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/zoneinfo")
+}
+
+fn berlin_bytes() -> Vec<u8> {
+    fs::read(fixtures().join("Europe/Berlin")).unwrap()
+}
+
+fn invalid(name: &str, reason: &str) -> Error {
+    Error::InvalidTimeZone {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[test]
+fn test_load_from_zoneinfo_dir() {
+    tz::set_zoneinfo_dir(fixtures());
+    assert_eq!(fixtures(), tz::zoneinfo_dir());
+    let zone = TimeZone::load("America/New_York").unwrap();
+    assert_eq!("America/New_York", zone.get_name());
+    assert_eq!("America/New_York", zone.to_string());
+    assert_eq!("EST5", zone.get_posix_tz().unwrap().to_string());
+    assert_eq!(
+        Err(Error::UnknownTimeZone {
+            name: "Mars/Olympus_Mons".to_string()
+        }),
+        TimeZone::load("Mars/Olympus_Mons")
+    );
+}
+
+#[test]
+fn test_rejects_names_outside_of_directory() {
+    for name in &[
+        "",
+        "../zoneinfo/Europe/Berlin",
+        "/etc/localtime",
+        "Europe/../Europe/Berlin",
+    ] {
+        assert_eq!(
+            Err(Error::UnknownTimeZone {
+                name: name.to_string()
+            }),
+            TimeZone::load_from(fixtures(), name)
+        );
+    }
+}
+
+#[test]
+fn test_local_time_types() {
+    let zone = TimeZone::load_from(fixtures(), "Europe/Berlin").unwrap();
+    let summer = zone.local_time_type_at(date_time(2018, 7, 1, 12, 0, 0));
+    assert_eq!(UtcOffset::new(2, 0, 0).unwrap(), summer.get_offset());
+    assert!(summer.is_dst());
+    assert_eq!("CEST", summer.get_abbreviation());
+    let winter = zone.local_time_type_at(date_time(2018, 12, 1, 12, 0, 0));
+    assert_eq!(UtcOffset::new(1, 0, 0).unwrap(), winter.get_offset());
+    assert!(!winter.is_dst());
+    assert_eq!("CET", winter.get_abbreviation());
+    let lmt = zone.local_time_type_at(date_time(1850, 1, 1, 0, 0, 0));
+    assert_eq!(UtcOffset::new(0, 53, 28).unwrap(), lmt.get_offset());
+    assert_eq!("LMT", lmt.get_abbreviation());
+    assert_eq!(
+        "CET",
+        zone.local_time_type_at(date_time(2030, 7, 1, 12, 0, 0))
+            .get_abbreviation()
+    );
+    assert_eq!(
+        "CEST",
+        zone.local_time_type_at(date_time(2018, 3, 25, 1, 0, 0))
+            .get_abbreviation()
+    );
+    assert_eq!(
+        "CET",
+        zone.local_time_type_at(date_time(2018, 3, 25, 0, 59, 0))
+            .get_abbreviation()
+    );
+}
+
+#[test]
+fn test_version_1_data() {
+    let mut bytes = berlin_bytes();
+    bytes[4] = 0;
+    let zone = TimeZone::from_tzif("Europe/Berlin", &bytes).unwrap();
+    assert_eq!(None, zone.get_posix_tz());
+    assert_eq!(
+        "CEST",
+        zone.local_time_type_at(date_time(2019, 7, 1, 12, 0, 0))
+            .get_abbreviation()
+    );
+}
+
+#[test]
+fn test_utc() {
+    let zone = TimeZone::utc();
+    assert_eq!("UTC", zone.get_name());
+    let utc = zone.local_time_type_at(date_time(2018, 7, 1, 12, 0, 0));
+    assert!(utc.get_offset().is_utc());
+    assert_eq!("UTC", utc.get_abbreviation());
+}
+
+#[test]
+fn test_invalid_data() {
+    let bytes = berlin_bytes();
+    assert_eq!(
+        Err(invalid("Test", "it is not a TZif file")),
+        TimeZone::from_tzif("Test", b"TZof2")
+    );
+    assert_eq!(
+        Err(invalid("Test", "the file ends unexpectedly")),
+        TimeZone::from_tzif("Test", &bytes[..100])
+    );
+    let mut unsupported = bytes.clone();
+    unsupported[4] = b'9';
+    assert_eq!(
+        Err(invalid("Test", "its TZif version is not supported")),
+        TimeZone::from_tzif("Test", &unsupported)
+    );
+    let mut no_footer = bytes.clone();
+    no_footer.pop();
+    assert_eq!(
+        Err(invalid("Test", "the footer does not end with a newline")),
+        TimeZone::from_tzif("Test", &no_footer)
+    );
+}
+
+/// Builds a TZif header with the given version byte and counts, in the order
+/// isutcnt, isstdcnt, leapcnt, timecnt, typecnt, and charcnt.
+fn tzif_header(version: u8, counts: [u32; 6]) -> Vec<u8> {
+    let mut header = b"TZif".to_vec();
+    header.push(version);
+    header.extend_from_slice(&[0; 15]);
+    for count in &counts {
+        header.extend_from_slice(&count.to_be_bytes());
+    }
+    header
+}
+
+#[test]
+fn test_truncated_data_with_huge_counts() {
+    let ends = || Err(invalid("Test", "the file ends unexpectedly"));
+    let header = tzif_header(0, [0, 0, 0, 0x4000_0000, 1, 1]);
+    assert_eq!(44, header.len());
+    assert_eq!(ends(), TimeZone::from_tzif("Test", &header));
+    let header = tzif_header(
+        0,
+        [u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX],
+    );
+    assert_eq!(ends(), TimeZone::from_tzif("Test", &header));
+
+    let mut bytes = tzif_header(b'2', [0, 0, 0, 0, 1, 1]);
+    bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0]);
+    bytes.extend(tzif_header(b'2', [0, 0, u32::MAX, 0x4000_0000, 1, 1]));
+    assert_eq!(ends(), TimeZone::from_tzif("Test", &bytes));
+}
+
+#[test]
+fn test_footer_after_last_transition() {
+    let zone = TimeZone::load_from(fixtures(), "Australia/Sydney").unwrap();
+    assert_eq!(
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+        zone.get_posix_tz().unwrap().to_string()
+    );
+    // The table ends in 2037, after which the footer gives the same rules.
+    for &year in &[2030, 2037, 2038, 2100, 9999] {
+        let summer = zone.local_time_type_at(date_time(year, 1, 15, 0, 0, 0));
+        assert_eq!("AEDT", summer.get_abbreviation());
+        assert_eq!(11, summer.get_offset().get_hours());
+        let winter = zone.local_time_type_at(date_time(year, 7, 15, 0, 0, 0));
+        assert_eq!("AEST", winter.get_abbreviation());
+    }
+    // Summer time ends at 03:00 AEDT on the first Sunday in April, 2050-04-03.
+    assert_eq!(
+        "AEDT",
+        zone.local_time_type_at(date_time(2050, 4, 2, 15, 59, 0))
+            .get_abbreviation()
+    );
+    assert_eq!(
+        "AEST",
+        zone.local_time_type_at(date_time(2050, 4, 2, 16, 0, 0))
+            .get_abbreviation()
+    );
+}
+
+#[test]
+fn test_from_posix_tz() {
+    let zone = TimeZone::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    assert_eq!("CET-1CEST,M3.5.0,M10.5.0/3", zone.get_name());
+    assert_eq!(
+        "CEST",
+        zone.local_time_type_at(date_time(2024, 7, 1, 12, 0, 0))
+            .get_abbreviation()
+    );
+    assert_eq!(
+        "CET",
+        zone.local_time_type_at(date_time(2024, 10, 27, 1, 0, 0))
+            .get_abbreviation()
+    );
+    match TimeZone::from_posix_tz("CET") {
+        Err(Error::ParseError { position: 3, .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_from_tz_env() {
+    tz::set_zoneinfo_dir(fixtures());
+    assert_eq!(Ok(TimeZone::utc()), TimeZone::from_tz_env(""));
+    let berlin = TimeZone::load("Europe/Berlin").unwrap();
+    assert_eq!(Ok(berlin.clone()), TimeZone::from_tz_env("Europe/Berlin"));
+    assert_eq!(Ok(berlin), TimeZone::from_tz_env(":Europe/Berlin"));
+    let path = fixtures().join("America/New_York");
+    let path = path.to_str().unwrap();
+    let new_york = TimeZone::from_tz_env(&format!(":{}", path)).unwrap();
+    assert_eq!(path, new_york.get_name());
+    assert_eq!(
+        "EST",
+        new_york
+            .local_time_type_at(date_time(2018, 12, 1, 12, 0, 0))
+            .get_abbreviation()
+    );
+    assert_eq!(
+        TimeZone::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3"),
+        TimeZone::from_tz_env("CET-1CEST,M3.5.0,M10.5.0/3")
+    );
+    assert_eq!(
+        Err(Error::UnknownTimeZone {
+            name: "Europe/Berln".to_string()
+        }),
+        TimeZone::from_tz_env("Europe/Berln")
+    );
+    match TimeZone::from_tz_env("CET-1CEST,M3.5.0") {
+        Err(Error::ParseError { position: 16, .. }) => (),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_local_time_zone_is_cached() {
+    assert_eq!(TimeZone::local(), TimeZone::local());
+}
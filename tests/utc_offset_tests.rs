@@ -0,0 +1,97 @@
+extern crate date_time;
+
+use date_time::error::TypeName;
+use date_time::utc_offset::UtcOffset;
+use date_time::Error;
+use std::str::FromStr;
+
+#[test]
+fn test_validity() {
+    assert!(UtcOffset::new(18, 0, 0).is_ok());
+    assert!(UtcOffset::new(-18, 0, 0).is_ok());
+    assert!(UtcOffset::new(0, -30, 0).is_ok());
+    assert!(UtcOffset::new(5, 45, 0).is_ok());
+    assert_eq!(
+        Err(Error::InvalidOffset { seconds: 64860 }),
+        UtcOffset::new(18, 1, 0)
+    );
+    assert!(UtcOffset::new(5, -30, 0).is_err());
+    assert!(UtcOffset::new(5, 60, 0).is_err());
+    assert!(UtcOffset::from_seconds(64800).is_ok());
+    assert!(UtcOffset::from_seconds(-64801).is_err());
+}
+
+#[test]
+fn test_most_negative_components() {
+    assert_eq!(
+        Err(Error::InvalidOffset { seconds: -7680 }),
+        UtcOffset::new(0, i8::MIN, 0)
+    );
+    assert_eq!(
+        Err(Error::InvalidOffset { seconds: -128 }),
+        UtcOffset::new(0, 0, i8::MIN)
+    );
+    assert!(UtcOffset::new(i8::MIN, 0, 0).is_err());
+    assert_eq!(
+        Err(Error::InvalidOffset { seconds: i32::MIN }),
+        UtcOffset::from_seconds(i32::MIN)
+    );
+}
+
+#[test]
+fn test_getters() {
+    let offset = UtcOffset::new(-5, -30, -15).unwrap();
+    assert_eq!(-5, offset.get_hours());
+    assert_eq!(-30, offset.get_minutes());
+    assert_eq!(-15, offset.get_seconds());
+    assert_eq!(-19815, offset.to_seconds());
+    assert!(offset.is_negative());
+    assert!(!offset.is_utc());
+    assert!(UtcOffset::utc().is_utc());
+    assert_eq!(UtcOffset::utc(), UtcOffset::default());
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!("+02:00", UtcOffset::new(2, 0, 0).unwrap().to_string());
+    assert_eq!("-05:30", UtcOffset::new(-5, -30, 0).unwrap().to_string());
+    assert_eq!("-00:30", UtcOffset::new(0, -30, 0).unwrap().to_string());
+    assert_eq!("+00:00", UtcOffset::utc().to_string());
+    assert_eq!(
+        "+00:19:32",
+        UtcOffset::from_seconds(1172).unwrap().to_string()
+    );
+    assert_eq!("+18:00", UtcOffset::max_value().to_string());
+    assert_eq!("-18:00", UtcOffset::min_value().to_string());
+}
+
+#[test]
+fn test_from_string() {
+    assert_eq!(UtcOffset::new(2, 0, 0), UtcOffset::from_str("+02:00"));
+    assert_eq!(UtcOffset::new(-5, -30, 0), UtcOffset::from_str("-0530"));
+    assert_eq!(UtcOffset::new(9, 0, 0), UtcOffset::from_str("+09"));
+    assert_eq!(
+        UtcOffset::from_seconds(1172),
+        UtcOffset::from_str("+00:19:32")
+    );
+    assert_eq!(Ok(UtcOffset::utc()), UtcOffset::from_str("Z"));
+    assert_eq!(
+        Err(Error::InvalidOffset { seconds: 68400 }),
+        UtcOffset::from_str("+19:00")
+    );
+    assert_eq!(
+        Err(Error::InvalidFormat {
+            type_name: TypeName::UtcOffset,
+            input: "02:00".to_string(),
+            expected: "+02:00"
+        }),
+        UtcOffset::from_str("02:00")
+    );
+    assert!(UtcOffset::from_str("+02:60").is_err());
+}
+
+#[test]
+fn test_ordering() {
+    assert!(UtcOffset::new(-5, 0, 0).unwrap() < UtcOffset::utc());
+    assert!(UtcOffset::new(5, 30, 0).unwrap() > UtcOffset::new(5, 0, 0).unwrap());
+}
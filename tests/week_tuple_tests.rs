@@ -0,0 +1,113 @@
+extern crate date_time;
+
+use date_time::date_tuple::DateTuple;
+use date_time::week_tuple::WeekTuple;
+use date_time::weekday::Weekday;
+use date_time::Error;
+use std::convert::TryFrom;
+
+#[test]
+fn test_validity() {
+    assert!(WeekTuple::new(2020, 53).is_ok());
+    assert!(WeekTuple::new(2015, 53).is_ok());
+    assert!(WeekTuple::new(2016, 52).is_ok());
+    assert!(WeekTuple::new(2016, 53).is_err());
+    assert!(WeekTuple::new(2016, 0).is_err());
+    assert!(WeekTuple::new(10000, 1).is_err());
+}
+
+#[test]
+fn test_weeks_in_year() {
+    assert_eq!(53, WeekTuple::weeks_in_year(2004));
+    assert_eq!(53, WeekTuple::weeks_in_year(2020));
+    assert_eq!(52, WeekTuple::weeks_in_year(2021));
+    assert_eq!(52, WeekTuple::weeks_in_year(9999));
+}
+
+#[test]
+fn test_getters() {
+    let tuple = WeekTuple::new(2024, 7).unwrap();
+    assert_eq!(2024, tuple.get_year());
+    assert_eq!(7, tuple.get_week());
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!(
+        String::from("2024-W07"),
+        WeekTuple::new(2024, 7).unwrap().to_string()
+    );
+    assert_eq!(String::from("0000-W01"), WeekTuple::min_value().to_string());
+}
+
+#[test]
+fn test_from_string() {
+    let tuple = WeekTuple::new(2024, 7).unwrap();
+    assert_eq!(tuple, str::parse("2024-W07").unwrap());
+    assert_eq!(tuple, str::parse("2024W07").unwrap());
+    match str::parse::<WeekTuple>("2021-W53") {
+        Err(Error::InvalidParsedValue { error, .. }) => match *error {
+            Error::InvalidWeek { weeks_in_year, .. } => assert_eq!(52, weeks_in_year),
+            e => panic!("Unexpected error: {:?}", e),
+        },
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert!(str::parse::<WeekTuple>("2024-07").is_err());
+    assert!(str::parse::<WeekTuple>("2024-W7").is_err());
+}
+
+#[test]
+fn test_comparisons() {
+    let tuple1 = WeekTuple::new(2020, 53).unwrap();
+    let tuple2 = WeekTuple::new(2021, 1).unwrap();
+    let tuple3 = WeekTuple::new(2021, 2).unwrap();
+    assert!(tuple1 < tuple2);
+    assert!(tuple2 < tuple3);
+    assert!(tuple3 >= tuple3);
+}
+
+#[test]
+fn test_from_date() {
+    let week = |y, m, d| WeekTuple::try_from(DateTuple::new(y, m, d).unwrap()).unwrap();
+    assert_eq!(WeekTuple::new(2024, 7).unwrap(), week(2024, 2, 14));
+    assert_eq!(WeekTuple::new(2019, 1).unwrap(), week(2018, 12, 31));
+    assert_eq!(WeekTuple::new(2020, 53).unwrap(), week(2021, 1, 3));
+    assert_eq!(WeekTuple::new(2021, 1).unwrap(), week(2021, 1, 4));
+    assert_eq!(WeekTuple::new(2009, 53).unwrap(), week(2010, 1, 1));
+    assert_eq!(WeekTuple::min_value(), week(0, 1, 3));
+    assert_eq!(WeekTuple::max_value(), week(9999, 12, 31));
+    assert!(WeekTuple::try_from(DateTuple::min_value()).is_err());
+    assert!(WeekTuple::try_from(DateTuple::new(0, 1, 2).unwrap()).is_err());
+}
+
+#[test]
+fn test_to_date() {
+    let tuple = WeekTuple::new(2020, 53).unwrap();
+    assert_eq!(
+        DateTuple::new(2020, 12, 28).unwrap(),
+        tuple.get_first_date()
+    );
+    assert_eq!(
+        DateTuple::new(2021, 1, 3).unwrap(),
+        tuple.to_date(Weekday::Sunday).unwrap()
+    );
+    assert_eq!(
+        DateTuple::new(0, 1, 3).unwrap(),
+        WeekTuple::min_value().get_first_date()
+    );
+    assert_eq!(
+        DateTuple::max_value(),
+        WeekTuple::max_value().to_date(Weekday::Friday).unwrap()
+    );
+    assert!(WeekTuple::max_value().to_date(Weekday::Saturday).is_err());
+}
+
+#[test]
+fn test_date_round_trip() {
+    let mut date = DateTuple::new(1999, 12, 1).unwrap();
+    while date < DateTuple::new(2030, 2, 1).unwrap() {
+        let week = WeekTuple::try_from(date).unwrap();
+        assert_eq!(date, week.to_date(date.weekday()).unwrap());
+        date = date.next_date();
+    }
+}
@@ -0,0 +1,50 @@
+extern crate date_time;
+
+use date_time::weekday::Weekday;
+
+#[test]
+fn test_to_string() {
+    assert_eq!(String::from("Monday"), Weekday::Monday.to_string());
+    assert_eq!(String::from("Wednesday"), Weekday::Wednesday.to_string());
+    assert_eq!(String::from("Sun"), Weekday::Sunday.to_short_string());
+}
+
+#[test]
+fn test_from_string() {
+    assert_eq!(Weekday::Tuesday, str::parse("Tuesday").unwrap());
+    assert_eq!(Weekday::Tuesday, str::parse("tue").unwrap());
+    assert_eq!(Weekday::Saturday, str::parse("SATURDAY").unwrap());
+    assert!(str::parse::<Weekday>("Tues").is_err());
+    assert!(str::parse::<Weekday>("").is_err());
+}
+
+#[test]
+fn test_numbering() {
+    assert_eq!(1, Weekday::Monday.number_from_monday());
+    assert_eq!(7, Weekday::Sunday.number_from_monday());
+    assert_eq!(0, Weekday::Sunday.number_from_sunday());
+    assert_eq!(6, Weekday::Saturday.number_from_sunday());
+    assert_eq!(
+        Weekday::Thursday,
+        Weekday::from_number_from_monday(4).unwrap()
+    );
+    assert_eq!(
+        Weekday::Sunday,
+        Weekday::from_number_from_sunday(0).unwrap()
+    );
+    assert_eq!(
+        Weekday::Monday,
+        Weekday::from_number_from_sunday(1).unwrap()
+    );
+    assert!(Weekday::from_number_from_monday(0).is_err());
+    assert!(Weekday::from_number_from_monday(8).is_err());
+    assert!(Weekday::from_number_from_sunday(7).is_err());
+}
+
+#[test]
+fn test_succ_and_pred() {
+    assert_eq!(Weekday::Tuesday, Weekday::Monday.succ());
+    assert_eq!(Weekday::Monday, Weekday::Sunday.succ());
+    assert_eq!(Weekday::Sunday, Weekday::Monday.pred());
+    assert_eq!(Weekday::Friday, Weekday::Saturday.pred());
+}
@@ -0,0 +1,111 @@
+extern crate date_time;
+
+use date_time::clock::FixedClock;
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::month_tuple::MonthTuple;
+use date_time::quarter_tuple::QuarterTuple;
+use date_time::year_tuple::YearTuple;
+
+#[test]
+fn test_this_year_does_not_panic() {
+    YearTuple::this_year();
+}
+
+#[test]
+fn test_this_year_with_clock() {
+    let clock = FixedClock::new(DateTimeTuple::from_rfc3339("2018-12-31T23:59:59Z").unwrap());
+    assert_eq!(
+        YearTuple::new(2018).unwrap(),
+        YearTuple::this_year_with_clock(&clock)
+    );
+    clock.set(DateTimeTuple::from_rfc3339("2019-01-01T00:00:00Z").unwrap());
+    assert_eq!(
+        YearTuple::new(2019).unwrap(),
+        YearTuple::this_year_with_clock(&clock)
+    );
+}
+
+#[test]
+fn test_validity() {
+    assert!(YearTuple::new(0).is_ok());
+    assert!(YearTuple::new(9999).is_ok());
+    assert!(YearTuple::new(10000).is_err());
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!(
+        String::from("2024"),
+        YearTuple::new(2024).unwrap().to_string()
+    );
+    assert_eq!(
+        String::from("0012"),
+        YearTuple::new(12).unwrap().to_string()
+    );
+}
+
+#[test]
+fn test_from_string() {
+    assert_eq!(YearTuple::new(2024).unwrap(), str::parse("2024").unwrap());
+    assert!(str::parse::<YearTuple>("24").is_err());
+    assert!(str::parse::<YearTuple>("20245").is_err());
+}
+
+#[test]
+fn test_next_and_previous_year() {
+    let tuple = YearTuple::new(2000).unwrap();
+    assert_eq!(YearTuple::new(2001).unwrap(), tuple.next_year());
+    assert_eq!(YearTuple::new(1999).unwrap(), tuple.previous_year());
+    assert_eq!(
+        YearTuple::new(9999).unwrap(),
+        YearTuple::new(9999).unwrap().next_year()
+    );
+    assert_eq!(
+        YearTuple::new(0).unwrap(),
+        YearTuple::new(0).unwrap().previous_year()
+    );
+}
+
+#[test]
+fn test_add_and_subtract_years() {
+    let mut tuple = YearTuple::new(2000).unwrap();
+    tuple.add_years(24);
+    assert_eq!(YearTuple::new(2024).unwrap(), tuple);
+    tuple.add_years(u16::MAX);
+    assert_eq!(YearTuple::new(9999).unwrap(), tuple);
+    tuple.subtract_years(u16::MAX);
+    assert_eq!(YearTuple::new(0).unwrap(), tuple);
+}
+
+#[test]
+fn test_comparisons() {
+    assert!(YearTuple::new(2000).unwrap() < YearTuple::new(2001).unwrap());
+}
+
+#[test]
+fn test_is_leap_year() {
+    assert!(YearTuple::new(2000).unwrap().is_leap_year());
+    assert!(!YearTuple::new(2100).unwrap().is_leap_year());
+}
+
+#[test]
+fn test_contents() {
+    let tuple = YearTuple::new(2024).unwrap();
+    let months = tuple.get_months();
+    assert_eq!(MonthTuple::new(2024, 1).unwrap(), months[0]);
+    assert_eq!(MonthTuple::new(2024, 12).unwrap(), months[11]);
+    let quarters = tuple.get_quarters();
+    assert_eq!(QuarterTuple::new(2024, 1).unwrap(), quarters[0]);
+    assert_eq!(QuarterTuple::new(2024, 4).unwrap(), quarters[3]);
+    assert_eq!(DateTuple::new(2024, 1, 1).unwrap(), tuple.get_first_date());
+    assert_eq!(DateTuple::new(2024, 12, 31).unwrap(), tuple.get_last_date());
+}
+
+#[test]
+fn test_conversions() {
+    let tuple = YearTuple::new(2024).unwrap();
+    assert_eq!(tuple, YearTuple::from(DateTuple::new(2024, 5, 6).unwrap()));
+    assert_eq!(tuple, YearTuple::from(MonthTuple::new(2024, 5).unwrap()));
+    assert_eq!(tuple, YearTuple::from(QuarterTuple::new(2024, 2).unwrap()));
+}
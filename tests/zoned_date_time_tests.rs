@@ -0,0 +1,197 @@
+extern crate date_time;
+
+mod common;
+
+use common::date_time;
+use date_time::date_time_tuple::DateTimeTuple;
+use date_time::date_tuple::DateTuple;
+use date_time::time_tuple::TimeTuple;
+use date_time::tz::TimeZone;
+use date_time::zoned_date_time::{LocalResult, ZonedDateTime};
+
+fn zone(name: &str) -> TimeZone {
+    let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/zoneinfo");
+    TimeZone::load_from(fixtures, name).unwrap()
+}
+
+fn from_utc(utc: DateTimeTuple, name: &str) -> ZonedDateTime {
+    ZonedDateTime::from_utc(utc, &zone(name)).unwrap()
+}
+
+fn from_local(local: DateTimeTuple, name: &str) -> LocalResult {
+    ZonedDateTime::from_local(local, &zone(name)).unwrap()
+}
+
+#[test]
+fn test_from_utc() {
+    let summer = from_utc(date_time(2018, 10, 2, 8, 30, 0), "Europe/Berlin");
+    assert_eq!(date_time(2018, 10, 2, 10, 30, 0), summer.get_date_time());
+    assert_eq!(2, summer.get_offset().get_hours());
+    assert_eq!("CEST", summer.get_abbreviation());
+    assert!(summer.is_dst());
+    assert_eq!(
+        "2018-10-02T10:30:00+02:00[Europe/Berlin]",
+        summer.to_string()
+    );
+    assert_eq!(date_time(2018, 10, 2, 8, 30, 0), summer.to_utc());
+    assert_eq!("Europe/Berlin", summer.get_time_zone().get_name());
+    assert_eq!(
+        "2018-10-02T10:30:00+02:00",
+        summer.to_offset_date_time().to_string()
+    );
+
+    let winter = from_utc(date_time(2018, 12, 24, 18, 0, 0), "Europe/Berlin");
+    assert_eq!(
+        "2018-12-24T19:00:00+01:00[Europe/Berlin]",
+        winter.to_string()
+    );
+    assert_eq!("CET", winter.get_abbreviation());
+    assert!(!winter.is_dst());
+
+    let lmt = from_utc(date_time(1850, 1, 1, 0, 0, 0), "Europe/Berlin");
+    assert_eq!(
+        "1850-01-01T00:53:28+00:53:28[Europe/Berlin]",
+        lmt.to_string()
+    );
+}
+
+#[test]
+fn test_with_time_zone() {
+    let berlin = from_utc(date_time(2018, 10, 2, 8, 30, 0), "Europe/Berlin");
+    let new_york = berlin.with_time_zone(&zone("America/New_York")).unwrap();
+    assert_eq!(
+        "2018-10-02T04:30:00-04:00[America/New_York]",
+        new_york.to_string()
+    );
+    assert_eq!("EDT", new_york.get_abbreviation());
+    assert_eq!(berlin, new_york);
+    let utc = berlin.with_time_zone(&TimeZone::utc()).unwrap();
+    assert_eq!("2018-10-02T08:30:00Z[UTC]", utc.to_string());
+    assert!(new_york < from_utc(date_time(2018, 10, 2, 8, 31, 0), "America/New_York"));
+}
+
+#[test]
+fn test_from_local_single() {
+    let result = from_local(date_time(2018, 10, 2, 10, 30, 0), "Europe/Berlin");
+    let expected = from_utc(date_time(2018, 10, 2, 8, 30, 0), "Europe/Berlin");
+    assert_eq!(LocalResult::Single(expected.clone()), result);
+    assert_eq!(Some(expected.clone()), result.clone().single());
+    assert_eq!(Some(expected.clone()), result.clone().earliest());
+    assert_eq!(Some(expected), result.latest());
+    let lmt = DateTimeTuple::new(
+        DateTuple::new(1850, 1, 1).unwrap(),
+        TimeTuple::new(0, 53, 28),
+    );
+    assert_eq!(
+        date_time(1850, 1, 1, 0, 0, 0),
+        from_local(lmt, "Europe/Berlin").single().unwrap().to_utc()
+    );
+}
+
+#[test]
+fn test_from_local_ambiguous() {
+    let result = from_local(date_time(2018, 10, 28, 2, 30, 0), "Europe/Berlin");
+    match result.clone() {
+        LocalResult::Ambiguous(earlier, later) => {
+            assert_eq!(
+                "2018-10-28T02:30:00+02:00[Europe/Berlin]",
+                earlier.to_string()
+            );
+            assert_eq!(
+                "2018-10-28T02:30:00+01:00[Europe/Berlin]",
+                later.to_string()
+            );
+            assert_eq!(date_time(2018, 10, 28, 0, 30, 0), earlier.to_utc());
+            assert_eq!(date_time(2018, 10, 28, 1, 30, 0), later.to_utc());
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert_eq!(None, result.clone().single());
+    assert_eq!(
+        "CEST",
+        result.clone().earliest().unwrap().get_abbreviation()
+    );
+    assert_eq!("CET", result.latest().unwrap().get_abbreviation());
+
+    let new_york = from_local(date_time(2018, 11, 4, 1, 30, 0), "America/New_York");
+    assert_eq!(
+        date_time(2018, 11, 4, 5, 30, 0),
+        new_york.clone().earliest().unwrap().to_utc()
+    );
+    assert_eq!(
+        date_time(2018, 11, 4, 6, 30, 0),
+        new_york.latest().unwrap().to_utc()
+    );
+}
+
+#[test]
+fn test_from_local_skipped() {
+    let result = from_local(date_time(2018, 3, 25, 2, 30, 0), "Europe/Berlin");
+    assert_eq!(
+        LocalResult::Skipped(from_utc(date_time(2018, 3, 25, 1, 0, 0), "Europe/Berlin")),
+        result
+    );
+    match result.clone() {
+        LocalResult::Skipped(transition) => assert_eq!(
+            "2018-03-25T03:00:00+02:00[Europe/Berlin]",
+            transition.to_string()
+        ),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert_eq!(None, result.clone().single());
+    assert_eq!(None, result.clone().earliest());
+    assert_eq!(None, result.latest());
+
+    match from_local(date_time(2018, 3, 11, 2, 0, 0), "America/New_York") {
+        LocalResult::Skipped(transition) => {
+            assert_eq!(date_time(2018, 3, 11, 7, 0, 0), transition.to_utc())
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match from_local(date_time(2018, 3, 11, 3, 0, 0), "America/New_York") {
+        LocalResult::Single(zdt) => assert_eq!("EDT", zdt.get_abbreviation()),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_out_of_range() {
+    let utc = DateTimeTuple::max_value();
+    assert!(ZonedDateTime::from_utc(utc, &zone("Europe/Berlin")).is_err());
+    assert!(ZonedDateTime::from_local(utc, &zone("America/New_York")).is_err());
+    assert!(ZonedDateTime::from_utc(utc, &TimeZone::utc()).is_ok());
+}
+
+#[test]
+fn test_posix_tz_rules() {
+    let rules = TimeZone::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    match ZonedDateTime::from_local(date_time(2030, 10, 27, 2, 30, 0), &rules).unwrap() {
+        LocalResult::Ambiguous(earlier, later) => {
+            assert_eq!(date_time(2030, 10, 27, 0, 30, 0), earlier.to_utc());
+            assert_eq!(date_time(2030, 10, 27, 1, 30, 0), later.to_utc());
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    match ZonedDateTime::from_local(date_time(2030, 3, 31, 2, 30, 0), &rules).unwrap() {
+        LocalResult::Skipped(transition) => assert_eq!(
+            "2030-03-31T03:00:00+02:00[CET-1CEST,M3.5.0,M10.5.0/3]",
+            transition.to_string()
+        ),
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    // The fixture's table ends in 2037, after which its footer applies.
+    let sydney = zone("Australia/Sydney");
+    match from_local(date_time(2050, 4, 3, 2, 30, 0), "Australia/Sydney") {
+        LocalResult::Ambiguous(earlier, later) => {
+            assert_eq!("AEDT", earlier.get_abbreviation());
+            assert_eq!("AEST", later.get_abbreviation());
+        }
+        r => panic!("Unexpected result: {:?}", r),
+    }
+    assert_eq!(
+        "2050-10-02T03:00:00+11:00[Australia/Sydney]",
+        ZonedDateTime::from_utc(date_time(2050, 10, 1, 16, 0, 0), &sydney)
+            .unwrap()
+            .to_string()
+    );
+}